@@ -1,6 +1,7 @@
-use error::{Error, ErrorObject, RequestError};
+use error::{Error, ErrorObject, OAuthError, OAuthErrorCode, RequestError};
 use reqwest;
-use reqwest::RequestBuilder;
+use reqwest::multipart::Form;
+use reqwest::{RequestBuilder, StatusCode};
 use reqwest::header::{
     HeaderMap, HeaderName, HeaderValue, AUTHORIZATION,
 };
@@ -27,6 +28,14 @@ impl Client {
         format!("https://api.stripe.com/v1/{}", &path[1..])
     }
 
+    fn files_url(path: &str) -> String {
+        format!("https://files.stripe.com/v1/{}", &path[1..])
+    }
+
+    fn connect_url(path: &str) -> String {
+        format!("https://connect.stripe.com/{}", &path[1..])
+    }
+
     pub fn new<Str: Into<String>>(secret_key: Str) -> Client {
         let client = reqwest::Client::new();
         Client {
@@ -71,6 +80,34 @@ impl Client {
         send(request)
     }
 
+    /// Sends a `multipart/form-data` request to the file upload API at `files.stripe.com`.
+    pub fn post_multipart<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        form: Form,
+    ) -> Result<T, Error> {
+        send(self.multipart_request(path, form))
+    }
+
+    fn multipart_request(&self, path: &str, form: Form) -> RequestBuilder {
+        let url = Client::files_url(path);
+        self.client.post(&url).headers(self.headers()).multipart(form)
+    }
+
+    /// Sends a form to Stripe Connect's OAuth endpoints at `connect.stripe.com`.
+    ///
+    /// Failed requests are reported as an `Error::OAuth` rather than an `Error::Stripe`.
+    pub fn post_oauth<T: serde::de::DeserializeOwned, F: serde::Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Result<T, Error> {
+        let url = Client::connect_url(path);
+        let request = self.client.post(&url).headers(self.headers());
+        let request = with_querystring(request, &form)?;
+        send_oauth(request)
+    }
+
     pub fn post_empty<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
         let url = Client::url(path);
         let request = self.client.post(&url).headers(self.headers());
@@ -115,12 +152,15 @@ fn with_querystring<T: serde::Serialize>(request: RequestBuilder, form: &T) -> R
     Ok(request.header(key, value).body(body))
 }
 
-fn send<T: serde::de::DeserializeOwned>(request: RequestBuilder) -> Result<T, Error> {
+fn read_response(request: RequestBuilder) -> Result<(StatusCode, String), Error> {
     let mut response = request.send()?;
     let mut body = String::with_capacity(4096);
     response.read_to_string(&mut body)?;
+    Ok((response.status(), body))
+}
 
-    let status = response.status();
+fn send<T: serde::de::DeserializeOwned>(request: RequestBuilder) -> Result<T, Error> {
+    let (status, body) = read_response(request)?;
     if !status.is_success() {
         let mut err = serde_json::from_str(&body).unwrap_or_else(|err| {
             let mut req = ErrorObject {
@@ -136,9 +176,26 @@ fn send<T: serde::de::DeserializeOwned>(request: RequestBuilder) -> Result<T, Er
     serde_json::from_str(&body).map_err(|err| Error::from(err))
 }
 
+fn send_oauth<T: serde::de::DeserializeOwned>(request: RequestBuilder) -> Result<T, Error> {
+    let (status, body) = read_response(request)?;
+    if !status.is_success() {
+        let mut err = serde_json::from_str(&body).unwrap_or_else(|err| OAuthError {
+            http_status: 0,
+            code: OAuthErrorCode::Unknown,
+            error_description: Some(format!("failed to deserialize error: {}", err)),
+        });
+        err.http_status = status.as_u16();
+        return Err(Error::from(err));
+    }
+
+    serde_json::from_str(&body).map_err(Error::from)
+}
+
 #[cfg(test)]
 mod tests {
-    use ::{Client, CustomerParams};
+    use ::{Client, CustomerParams, File, FilePurpose};
+    use reqwest::header::CONTENT_TYPE;
+    use reqwest::multipart::Part;
     use std::collections::HashMap;
     use super::with_querystring;
 
@@ -167,4 +224,18 @@ mod tests {
             assert_eq!(body, "Some(Body { kind: b\"email=jdoe%40example.org&metadata[any]=thing\" })");
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn build_multipart_request() {
+        let client = Client::new("sk_test_123");
+        let file = Part::bytes(vec![1, 2, 3]);
+        let form = File::upload_form(FilePurpose::DisputeEvidence, "receipt.pdf", file);
+        let boundary = form.boundary().to_string();
+        let request = client.multipart_request("/files", form).build().unwrap();
+        assert_eq!(request.url().as_str(), "https://files.stripe.com/v1/files");
+        assert_eq!(
+            request.headers()[CONTENT_TYPE],
+            format!("multipart/form-data; boundary={}", boundary).as_str()
+        );
+    }
+}
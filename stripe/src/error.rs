@@ -13,6 +13,8 @@ use std::num::ParseIntError;
 pub enum Error {
     /// An error reported by Stripe.
     Stripe(RequestError),
+    /// An error reported by Stripe Connect's OAuth endpoints.
+    OAuth(OAuthError),
     /// A networking error communicating with the Stripe server.
     Http(reqwest::Error),
     /// An error reading the response body.
@@ -30,6 +32,7 @@ impl fmt::Display for Error {
         f.write_str(error::Error::description(self))?;
         match *self {
             Error::Stripe(ref err) => write!(f, ": {}", err),
+            Error::OAuth(ref err) => write!(f, ": {}", err),
             Error::Http(ref err) => write!(f, ": {}", err),
             Error::Io(ref err) => write!(f, ": {}", err),
             Error::Conversion(ref err) => write!(f, ": {}", err),
@@ -43,6 +46,7 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::Stripe(_) => "error reported by stripe",
+            Error::OAuth(_) => "error reported by stripe connect",
             Error::Http(_) => "error communicating with stripe",
             Error::Io(_) => "error reading response from stripe",
             Error::Conversion(_) => "error converting between wire format and Rust types",
@@ -54,6 +58,7 @@ impl error::Error for Error {
     fn cause(&self) -> Option<&error::Error> {
         match *self {
             Error::Stripe(ref err) => Some(err),
+            Error::OAuth(ref err) => Some(err),
             Error::Http(ref err) => Some(err),
             Error::Io(ref err) => Some(err),
             Error::Conversion(ref err) => Some(&**err),
@@ -69,6 +74,12 @@ impl From<RequestError> for Error {
     }
 }
 
+impl From<OAuthError> for Error {
+    fn from(err: OAuthError) -> Error {
+        Error::OAuth(err)
+    }
+}
+
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Error {
         Error::Http(err)
@@ -271,6 +282,66 @@ pub struct ErrorObject {
     pub error: RequestError,
 }
 
+/// The list of possible values for an OAuthError's code.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-token-errors.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthErrorCode {
+    AccessDenied,
+    InvalidClient,
+    InvalidGrant,
+    InvalidRedirectUri,
+    InvalidRequest,
+    InvalidScope,
+    UnsupportedGrantType,
+    UnsupportedResponseType,
+    #[serde(other)]
+    Unknown,
+}
+
+impl fmt::Display for OAuthErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+    }
+}
+
+/// An error reported by stripe in the response to a Connect OAuth request.
+///
+/// Unlike API errors these are not nested in an `error` object.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference.
+#[derive(Debug, Deserialize)]
+pub struct OAuthError {
+    /// The HTTP status in the response.
+    #[serde(skip_deserializing)]
+    pub http_status: u16,
+
+    /// The kind of error returned.
+    #[serde(rename = "error")]
+    pub code: OAuthErrorCode,
+
+    /// A human-readable message providing more details about the error.
+    #[serde(default)]
+    pub error_description: Option<String>,
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", self.code, self.http_status)?;
+        if let Some(ref description) = self.error_description {
+            write!(f, ": {}", description)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for OAuthError {
+    fn description(&self) -> &str {
+        self.error_description.as_deref().unwrap_or("oauth error")
+    }
+}
+
 /// An error encountered when communicating with the Stripe API webhooks.
 #[derive(Debug)]
 pub enum WebhookError {
@@ -8,6 +8,11 @@ macro_rules! id {
             pub fn prefix() -> &'static str {
                 $prefix
             }
+
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
         }
 
         impl ::std::fmt::Display for $newtype_name {
@@ -130,12 +135,28 @@ impl ::std::error::Error for ParseIdError {
     }
 }
 
+id!(AccountId, "acct_");
 id!(BankAccountId, "ba_");
+id!(BankTokenId, "btok_");
 id!(CardId, "card_");
+id!(ChargeId, "ch_");
+id!(CustomerId, "cus_");
+id!(PaymentId, "py_");
+id!(PaymentIntentId, "pi_");
 id!(SourceId, "src_");
 id!(TokenId, "tok_");
+id!(TransferId, "tr_");
+id!(TransferReversalId, "trr_");
 id!(PaymentSourceId {
     BankAcccount(BankAccountId),
     Card(CardId),
     Source(SourceId),
 });
+id!(ExternalAccountTokenId {
+    BankToken(BankTokenId),
+    Token(TokenId),
+});
+id!(SourceTransactionId {
+    Charge(ChargeId),
+    Payment(PaymentId),
+});
@@ -51,8 +51,18 @@
 
 #![deny(warnings)]
 
+#[cfg(feature = "webhooks-actix")]
+extern crate actix_web;
+#[cfg(feature = "webhooks-axum")]
+extern crate axum;
 extern crate chrono;
+#[cfg(any(feature = "webhooks-actix", feature = "webhooks-hyper"))]
+extern crate futures;
+#[cfg(feature = "webhooks-axum")]
+extern crate futures_util;
 extern crate hmac;
+#[cfg(feature = "webhooks-hyper")]
+extern crate hyper;
 extern crate reqwest;
 extern crate serde;
 #[macro_use]
@@ -66,9 +76,11 @@ mod error;
 mod ids;
 mod params;
 mod resources;
+#[cfg(any(feature = "webhooks-actix", feature = "webhooks-axum", feature = "webhooks-hyper"))]
+pub mod webhooks;
 
 pub use client::{Client, Params};
-pub use error::{Error, ErrorCode, ErrorType, RequestError};
+pub use error::{Error, ErrorCode, ErrorType, OAuthError, OAuthErrorCode, RequestError};
 pub use ids::*;
-pub use params::{List, Metadata, RangeBounds, RangeQuery, Timestamp};
+pub use params::{Expandable, List, Metadata, RangeBounds, RangeQuery, Timestamp};
 pub use resources::*;
@@ -31,7 +31,8 @@ impl<T: DeserializeOwned> List<T> {
     pub fn get_next(client: &Client, url: &str, last_id: &str) -> Result<List<T>, Error> {
         if url.starts_with("/v1/") {
             let mut url = url.trim_left_matches("/v1/").to_string();
-            url.push_str(&format!("?starting_after={}", last_id));
+            let separator = if url.contains('?') { '&' } else { '?' };
+            url.push_str(&format!("{}starting_after={}", separator, last_id));
 
             client.get(&url)
         } else {
@@ -74,6 +75,34 @@ impl<T: Identifiable + DeserializeOwned> List<T> {
     }
 }
 
+/// A field which holds the id of an object, unless it was expanded into the object itself.
+///
+/// For more details see https://stripe.com/docs/api#expanding_objects.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Expandable<T> {
+    Id(String),
+    Object(Box<T>),
+}
+
+impl<T: Identifiable> Expandable<T> {
+    /// The id of the object, whether or not it was expanded.
+    pub fn id(&self) -> &str {
+        match *self {
+            Expandable::Id(ref id) => id,
+            Expandable::Object(ref object) => object.id(),
+        }
+    }
+
+    /// The object, if it was expanded.
+    pub fn as_object(&self) -> Option<&T> {
+        match *self {
+            Expandable::Id(_) => None,
+            Expandable::Object(ref object) => Some(object),
+        }
+    }
+}
+
 pub type Metadata = HashMap<String, String>;
 pub type Timestamp = i64;
 
@@ -1,6 +1,9 @@
-use params::{Identifiable, List, Metadata, Timestamp};
-use resources::BankAccount;
-use serde_json as json;
+use client::Client;
+use error::Error;
+use ids::AccountId;
+use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::{Address, Currency, Deleted, ExternalAccount};
+use serde_qs as qs;
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct DeclineChargeDetails {
@@ -30,17 +33,221 @@ pub struct TOSAcceptanceDetails {
     pub user_agent: Option<String>,
 }
 
-/// The set of parameters that can be used when creating an account for users.
+/// An enum representing the possible values of an `Account`'s `type` field.
 ///
-/// For more details see https://stripe.com/docs/api#create_account.
+/// For more details see https://stripe.com/docs/api#account_object-type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountType {
+    Standard,
+    Express,
+    Custom,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of a `LegalEntity`'s `type` field.
+///
+/// For more details see https://stripe.com/docs/api#account_object-legal_entity-type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LegalEntityType {
+    Individual,
+    Company,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of a `LegalEntityVerification`'s `status` field.
+///
+/// For more details see https://stripe.com/docs/api#account_object-legal_entity-verification-status.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LegalEntityVerificationStatus {
+    Unverified,
+    Pending,
+    Verified,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DateOfBirth {
+    pub day: Option<u32>,
+    pub month: Option<u32>,
+    pub year: Option<u32>,
+}
+
+/// The state of the identity verification of a legal entity or additional owner.
+///
+/// For more details see https://stripe.com/docs/api#account_object-legal_entity-verification.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LegalEntityVerification {
+    pub details: Option<String>,
+    pub details_code: Option<String>,
+    pub document: Option<String>,
+    pub status: LegalEntityVerificationStatus,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AdditionalOwner {
+    pub address: Option<Address>,
+    pub dob: Option<DateOfBirth>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub maiden_name: Option<String>,
+    #[serde(default)]
+    pub personal_id_number_provided: bool,
+    pub verification: Option<LegalEntityVerification>,
+}
+
+/// The individual or business which is responsible for an account.
+///
+/// For more details see https://stripe.com/docs/api#account_object-legal_entity.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LegalEntity {
+    #[serde(default)]
+    pub additional_owners: Option<Vec<AdditionalOwner>>,
+    pub address: Option<Address>,
+    pub business_name: Option<String>,
+    #[serde(default)]
+    pub business_tax_id_provided: bool,
+    pub dob: Option<DateOfBirth>,
+    pub first_name: Option<String>,
+    pub gender: Option<String>,
+    pub last_name: Option<String>,
+    pub maiden_name: Option<String>,
+    pub personal_address: Option<Address>,
+    #[serde(default)]
+    pub personal_id_number_provided: bool,
+    pub phone_number: Option<String>,
+    #[serde(default)]
+    pub ssn_last_4_provided: bool,
+    #[serde(rename = "type")]
+    pub legal_entity_type: Option<LegalEntityType>,
+    pub verification: Option<LegalEntityVerification>,
+}
+
+/// The information which is still needed to verify an account.
+///
+/// For more details see https://stripe.com/docs/api#account_object-verification.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccountVerification {
+    pub disabled_reason: Option<String>,
+    pub due_by: Option<Timestamp>,
+    #[serde(default)]
+    pub fields_needed: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LegalEntityVerificationParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when providing legal entity details for an account.
+///
+/// For more details see https://stripe.com/docs/api#update_account-legal_entity.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LegalEntityParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_tax_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<DateOfBirth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_id_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_entity_type: Option<LegalEntityType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<LegalEntityVerificationParams<'a>>,
+}
+
+/// The set of parameters that can be used when creating or updating an account.
+///
+/// For more details see https://stripe.com/docs/api#create_account and https://stripe.com/docs/api#update_account.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AccountParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_url: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<&'a str>, // (country the account holder resides in)
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub debit_negative_balances: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<&'a str>, // (required if account type is standard)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_entity: Option<LegalEntityParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_phone: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tos_acceptance: Option<TOSAcceptanceDetails>,
+    /// The type of account to create; this can't be changed once the account is created.
     #[serde(rename = "type")]
-    pub account_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<AccountType>,
+}
+
+/// The set of parameters that can be used when listing connected accounts.
+///
+/// For more details see https://stripe.com/docs/api#list_accounts.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AccountListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+/// An enum representing the possible reasons for rejecting an account.
+///
+/// For more details see https://stripe.com/docs/api#reject_account-reason.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountRejectReason {
+    Fraud,
+    TermsOfService,
+    Other,
+}
+
+/// The set of parameters that can be used when rejecting an account.
+///
+/// For more details see https://stripe.com/docs/api#reject_account.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccountRejectParams {
+    pub reason: AccountRejectReason,
 }
 
 /// The resource representing a Stripe account.
@@ -48,37 +255,190 @@ pub struct AccountParams<'a> {
 /// For more details see https://stripe.com/docs/api#account.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Account {
-    pub id: String,
+    pub id: AccountId,
     pub object: String,
-    pub business_name: String,
+    pub business_name: Option<String>,
     pub business_url: Option<String>,
-    pub charges_enabed: bool,
-    pub country: String,
+    #[serde(default)]
+    pub charges_enabled: bool,
+    pub country: Option<String>,
     pub debit_negative_balances: Option<bool>,
     pub decline_charge_on: Option<DeclineChargeDetails>,
-    pub default_currency: String,
+    pub default_currency: Option<Currency>,
+    #[serde(default)]
     pub details_submitted: bool,
-    pub display_name: String,
-    pub email: String,
-    pub external_accounts: List<BankAccount>,
-    pub legal_entity: Option<json::Value>,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    pub external_accounts: Option<List<ExternalAccount>>,
+    pub legal_entity: Option<LegalEntity>,
+    #[serde(default)]
     pub metadata: Metadata,
     pub payout_schedule: Option<PayoutScheduleDetails>,
     pub payout_statement_descriptor: Option<String>,
+    #[serde(default)]
     pub payouts_enabled: bool,
     pub product_description: Option<String>,
-    pub statement_descriptor: String,
-    pub support_email: String,
-    pub support_phone: String,
-    pub timezone: String,
+    pub statement_descriptor: Option<String>,
+    pub support_email: Option<String>,
+    pub support_phone: Option<String>,
+    pub timezone: Option<String>,
     pub tos_acceptance: Option<TOSAcceptanceDetails>, // (who accepted Stripe's terms of service)
     #[serde(rename = "type")]
-    pub account_type: Option<String>, // (Stripe, Custom, or Express)
-    pub verification: Option<json::Value>,
+    pub account_type: Option<AccountType>,
+    pub verification: Option<AccountVerification>,
 }
 
 impl Identifiable for Account {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
+    }
+}
+
+impl Account {
+    /// Creates a new connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_account.
+    pub fn create(client: &Client, params: AccountParams) -> Result<Account, Error> {
+        client.post("/accounts", params)
+    }
+
+    /// Retrieves the details of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_account.
+    pub fn retrieve(client: &Client, account_id: &AccountId) -> Result<Account, Error> {
+        client.get(&format!("/accounts/{}", account_id))
+    }
+
+    /// Retrieves the details of the account the client is authenticated as.
+    ///
+    /// This is the platform account unless the client sets a `Stripe-Account` header.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_account.
+    pub fn retrieve_current(client: &Client) -> Result<Account, Error> {
+        client.get("/account")
+    }
+
+    /// Updates a connected account's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_account.
+    pub fn update(
+        client: &Client,
+        account_id: &AccountId,
+        params: AccountParams,
+    ) -> Result<Account, Error> {
+        client.post(&format!("/accounts/{}", account_id), params)
+    }
+
+    /// Deletes a custom or express account.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_account.
+    pub fn delete(client: &Client, account_id: &AccountId) -> Result<Deleted, Error> {
+        client.delete(&format!("/accounts/{}", account_id))
+    }
+
+    /// Rejects a custom account, e.g. because it is suspected of fraud.
+    ///
+    /// For more details see https://stripe.com/docs/api#reject_account.
+    pub fn reject(
+        client: &Client,
+        account_id: &AccountId,
+        params: AccountRejectParams,
+    ) -> Result<Account, Error> {
+        client.post(&format!("/accounts/{}/reject", account_id), params)
+    }
+
+    /// Lists the accounts connected to the platform.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_accounts.
+    pub fn list(client: &Client, params: AccountListParams) -> Result<List<Account>, Error> {
+        client.get(&format!("/accounts?{}", qs::to_string(&params)?))
+    }
+}
+
+/// The resource representing a single-use login link to an Express account's dashboard.
+///
+/// For more details see https://stripe.com/docs/api#login_link_object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LoginLink {
+    pub object: String,
+    pub created: Timestamp,
+    pub url: String,
+}
+
+#[derive(Serialize)]
+struct LoginLinkParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_url: Option<&'a str>,
+}
+
+impl LoginLink {
+    /// Creates a login link for an Express account's dashboard.
+    ///
+    /// The `redirect_url` is where the user is sent after logging out of the dashboard.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_login_link.
+    pub fn create(
+        client: &Client,
+        account_id: &AccountId,
+        redirect_url: Option<&str>,
+    ) -> Result<LoginLink, Error> {
+        let params = LoginLinkParams { redirect_url };
+        client.post(&format!("/accounts/{}/login_links", account_id), params)
+    }
+}
+
+/// An enum representing the possible values of an `AccountLinkParams`'s `type` field.
+///
+/// For more details see https://stripe.com/docs/api#create_account_link-type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountLinkType {
+    AccountOnboarding,
+    AccountUpdate,
+}
+
+/// An enum representing the possible values of an `AccountLinkParams`'s `collect` field.
+///
+/// For more details see https://stripe.com/docs/api#create_account_link-collect.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountLinkCollect {
+    CurrentlyDue,
+    EventuallyDue,
+}
+
+/// The set of parameters that can be used when creating an account link.
+///
+/// For more details see https://stripe.com/docs/api#create_account_link.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccountLinkParams<'a> {
+    pub account: AccountId,
+    /// Where the user is sent if the link has expired or was already visited.
+    pub refresh_url: &'a str,
+    /// Where the user is sent after leaving or completing the flow.
+    pub return_url: &'a str,
+    #[serde(rename = "type")]
+    pub link_type: AccountLinkType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collect: Option<AccountLinkCollect>,
+}
+
+/// The resource representing a single-use link to Stripe's hosted onboarding flow.
+///
+/// For more details see https://stripe.com/docs/api#account_link_object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccountLink {
+    pub object: String,
+    pub created: Timestamp,
+    pub expires_at: Timestamp,
+    pub url: String,
+}
+
+impl AccountLink {
+    /// Creates a link for a connected account to provide its onboarding or update details.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_account_link.
+    pub fn create(client: &Client, params: AccountLinkParams) -> Result<AccountLink, Error> {
+        client.post("/account_links", params)
     }
 }
@@ -1,5 +1,8 @@
-use params::{Identifiable, List, Timestamp};
-use resources::{Currency, Refund};
+use client::Client;
+use error::Error;
+use params::{Expandable, Identifiable, List, RangeQuery, Timestamp};
+use resources::{ApplicationFeeRefund, Charge, Currency};
+use serde_qs as qs;
 
 /// The resource representing a Stripe application fee.
 ///
@@ -10,16 +13,16 @@ pub struct ApplicationFee {
     pub object: String,
     pub account: String,
     pub amount: u64,
-    pub amount_refunded: i64,
+    pub amount_refunded: u64,
     pub application: String,
     pub balance_transaction: String,
     pub charge: String,
     pub created: Timestamp,
     pub currency: Currency,
     pub livemode: bool,
-    pub originating_transaction: Option<String>,
+    pub originating_transaction: Option<Expandable<Charge>>,
     pub refunded: bool,
-    pub refunds: List<Refund>,
+    pub refunds: List<ApplicationFeeRefund>,
 }
 
 impl Identifiable for ApplicationFee {
@@ -27,3 +30,41 @@ impl Identifiable for ApplicationFee {
         &self.id
     }
 }
+
+/// The set of parameters that can be used when listing application fees.
+///
+/// For more details see https://stripe.com/docs/api#list_application_fees.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplicationFeeListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub expand: Vec<&'a str>, // e.g. "data.originating_transaction"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl ApplicationFee {
+    /// Retrieves the details of an application fee.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_application_fee.
+    pub fn retrieve(client: &Client, fee_id: &str) -> Result<ApplicationFee, Error> {
+        client.get(&format!("/application_fees/{}", fee_id))
+    }
+
+    /// Lists the application fees collected by the platform.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_application_fees.
+    pub fn list(
+        client: &Client,
+        params: ApplicationFeeListParams,
+    ) -> Result<List<ApplicationFee>, Error> {
+        client.get(&format!("/application_fees?{}", qs::to_string(&params)?))
+    }
+}
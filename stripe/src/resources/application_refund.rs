@@ -1,5 +1,8 @@
-use params::{Identifiable, Metadata, Timestamp};
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, Metadata, Timestamp};
 use resources::Currency;
+use serde_qs as qs;
 
 /// The resource representing a Stripe application fee refund.
 ///
@@ -21,3 +24,85 @@ impl Identifiable for ApplicationFeeRefund {
         &self.id
     }
 }
+
+/// The set of parameters that can be used when refunding an application fee.
+///
+/// For more details see https://stripe.com/docs/api#create_fee_refund.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplicationFeeRefundParams {
+    /// The amount to refund, which defaults to the entire application fee.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when updating an application fee refund.
+///
+/// For more details see https://stripe.com/docs/api#update_fee_refund.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplicationFeeRefundUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing application fee refunds.
+///
+/// For more details see https://stripe.com/docs/api#list_fee_refunds.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplicationFeeRefundListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl ApplicationFeeRefund {
+    /// Refunds all or part of an application fee.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_fee_refund.
+    pub fn create(
+        client: &Client,
+        fee_id: &str,
+        params: ApplicationFeeRefundParams,
+    ) -> Result<ApplicationFeeRefund, Error> {
+        client.post(&format!("/application_fees/{}/refunds", fee_id), params)
+    }
+
+    /// Retrieves the details of an application fee refund.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_fee_refund.
+    pub fn retrieve(
+        client: &Client,
+        fee_id: &str,
+        refund_id: &str,
+    ) -> Result<ApplicationFeeRefund, Error> {
+        client.get(&format!("/application_fees/{}/refunds/{}", fee_id, refund_id))
+    }
+
+    /// Updates an application fee refund's metadata.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_fee_refund.
+    pub fn update(
+        client: &Client,
+        fee_id: &str,
+        refund_id: &str,
+        params: ApplicationFeeRefundUpdateParams,
+    ) -> Result<ApplicationFeeRefund, Error> {
+        client.post(&format!("/application_fees/{}/refunds/{}", fee_id, refund_id), params)
+    }
+
+    /// Lists the refunds of an application fee.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_fee_refunds.
+    pub fn list(
+        client: &Client,
+        fee_id: &str,
+        params: ApplicationFeeRefundListParams,
+    ) -> Result<List<ApplicationFeeRefund>, Error> {
+        let query = qs::to_string(&params)?;
+        client.get(&format!("/application_fees/{}/refunds?{}", fee_id, query))
+    }
+}
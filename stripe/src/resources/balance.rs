@@ -1,27 +1,102 @@
-use params::{Identifiable, List, Timestamp};
-use resources::{Currency, PaymentSource};
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, RangeQuery, Timestamp};
+use resources::{
+    ApplicationFee, ApplicationFeeRefund, Charge, Currency, Dispute, Payout, Refund, Transfer,
+    TransferReversal,
+};
 use serde_json as json;
+use serde_qs as qs;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FeeDetails {
-    pub amount: u64,
-    pub application: String,
+    pub amount: i64,
+    pub application: Option<String>,
     pub currency: Currency,
-    pub description: String,
+    pub description: Option<String>,
     #[serde(rename = "type")]
     pub fee_type: String, // (application_fee, stripe_fee, or tax)
 }
 
+/// The breakdown of a balance amount by the type of source it came from.
+///
+/// For more details see https://stripe.com/docs/api#balance_object-available-source_types.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BalanceSourceTypes {
+    #[serde(default)]
+    pub bank_account: Option<i64>,
+    #[serde(default)]
+    pub card: Option<i64>,
+}
+
+/// The funds in a Stripe balance for a single currency.
+///
+/// For more details see https://stripe.com/docs/api#balance_object-available.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BalanceAmount {
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(default)]
+    pub source_types: Option<BalanceSourceTypes>,
+}
+
 /// The resource representing a Stripe account balance.
 ///
 /// For more details see https://stripe.com/docs/api#balance_object.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Balance {
     pub object: String,
-    pub available: Vec<json::Value>,
-    pub connect_reserved: Vec<json::Value>,
+    pub available: Vec<BalanceAmount>,
+    #[serde(default)]
+    pub connect_reserved: Vec<BalanceAmount>,
     pub livemode: bool,
-    pub pending: Vec<json::Value>,
+    pub pending: Vec<BalanceAmount>,
+}
+
+impl Balance {
+    /// Retrieves the current account balance.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_balance.
+    pub fn retrieve(client: &Client) -> Result<Balance, Error> {
+        client.get("/balance")
+    }
+}
+
+/// An enum representing the possible values of a `BalanceTransaction`'s `type` field.
+///
+/// For more details see https://stripe.com/docs/api#balance_transaction_object-type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceTransactionType {
+    Adjustment,
+    ApplicationFee,
+    ApplicationFeeRefund,
+    Charge,
+    Payment,
+    PaymentFailureRefund,
+    PaymentRefund,
+    Payout,
+    PayoutCancel,
+    PayoutFailure,
+    Refund,
+    StripeFee,
+    Transfer,
+    TransferRefund,
+    Validation,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of a `BalanceTransaction`'s `status` field.
+///
+/// For more details see https://stripe.com/docs/api#balance_transaction_object-status.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceTransactionStatus {
+    Available,
+    Pending,
+    #[serde(other)]
+    Other,
 }
 
 /// The resource representing a Stripe balance transaction.
@@ -31,18 +106,19 @@ pub struct Balance {
 pub struct BalanceTransaction {
     pub id: String,
     pub object: String,
-    pub amount: u64,
+    pub amount: i64,
     pub available_on: Timestamp,
     pub created: Timestamp,
     pub currency: Currency,
-    pub description: String,
-    pub fee: u64,
-    pub fee_details: List<FeeDetails>,
-    pub net: u64,
-    pub source: PaymentSource,
-    pub status: String,
+    pub description: Option<String>,
+    pub fee: i64,
+    pub fee_details: Vec<FeeDetails>,
+    pub net: i64,
+    #[serde(default)]
+    pub source: Option<BalanceTransactionSource>,
+    pub status: BalanceTransactionStatus,
     #[serde(rename = "type")]
-    pub transaction_type: String,
+    pub transaction_type: BalanceTransactionType,
 }
 
 impl Identifiable for BalanceTransaction {
@@ -50,3 +126,124 @@ impl Identifiable for BalanceTransaction {
         &self.id
     }
 }
+
+/// The object which caused a balance transaction.
+///
+/// This is the id of the object unless the `source` field was expanded, in which case
+/// it is deserialized into the corresponding resource.
+///
+/// For more details see https://stripe.com/docs/api#balance_transaction_object-source.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum BalanceTransactionSource {
+    Id(String),
+    ApplicationFee(Box<ApplicationFee>),
+    ApplicationFeeRefund(Box<ApplicationFeeRefund>),
+    Charge(Box<Charge>),
+    Dispute(Box<Dispute>),
+    Payout(Box<Payout>),
+    Refund(Box<Refund>),
+    Transfer(Box<Transfer>),
+    TransferReversal(Box<TransferReversal>),
+    /// An expanded source of a kind which isn't supported by this library (yet?),
+    /// or which doesn't match the library's definition of its resource.
+    Other(json::Value),
+}
+
+impl BalanceTransactionSource {
+    /// The id of the source object, whether or not it was expanded.
+    pub fn id(&self) -> Option<&str> {
+        match *self {
+            BalanceTransactionSource::Id(ref id) => Some(id),
+            BalanceTransactionSource::ApplicationFee(ref fee) => Some(fee.id()),
+            BalanceTransactionSource::ApplicationFeeRefund(ref refund) => Some(refund.id()),
+            BalanceTransactionSource::Charge(ref charge) => Some(charge.id()),
+            BalanceTransactionSource::Dispute(ref dispute) => Some(dispute.id()),
+            BalanceTransactionSource::Payout(ref payout) => Some(payout.id()),
+            BalanceTransactionSource::Refund(ref refund) => Some(refund.id()),
+            BalanceTransactionSource::Transfer(ref transfer) => Some(transfer.id()),
+            BalanceTransactionSource::TransferReversal(ref reversal) => Some(reversal.id()),
+            BalanceTransactionSource::Other(ref value) => value["id"].as_str(),
+        }
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BalanceTransactionSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::de::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        // N.B. the resource structs contain an `object` field of their own, so the
+        //      `object` tag is read up-front rather than with `#[serde(tag = "object")]`
+        let value = json::Value::deserialize(deserializer)?;
+        let object = match value {
+            json::Value::String(id) => return Ok(BalanceTransactionSource::Id(id)),
+            json::Value::Object(ref map) => map.get("object").and_then(|o| o.as_str()).map(|o| o.to_string()),
+            _ => None,
+        };
+        // N.B. a source which doesn't match its resource (e.g. because Stripe added a new
+        //      enum value) falls back to `Other`, rather than failing the whole transaction
+        let source = match object.as_deref() {
+            Some("application_fee") => json::from_value(value.clone()).map(BalanceTransactionSource::ApplicationFee),
+            Some("fee_refund") => json::from_value(value.clone()).map(BalanceTransactionSource::ApplicationFeeRefund),
+            Some("charge") => json::from_value(value.clone()).map(BalanceTransactionSource::Charge),
+            Some("dispute") => json::from_value(value.clone()).map(BalanceTransactionSource::Dispute),
+            Some("payout") => json::from_value(value.clone()).map(BalanceTransactionSource::Payout),
+            Some("refund") => json::from_value(value.clone()).map(BalanceTransactionSource::Refund),
+            Some("transfer") => json::from_value(value.clone()).map(BalanceTransactionSource::Transfer),
+            Some("transfer_reversal") => json::from_value(value.clone()).map(BalanceTransactionSource::TransferReversal),
+            Some(_) => return Ok(BalanceTransactionSource::Other(value)),
+            None => return Err(D::Error::custom("expected an id or an object for balance transaction source")),
+        };
+        Ok(source.unwrap_or(BalanceTransactionSource::Other(value)))
+    }
+}
+
+/// The set of parameters that can be used when listing balance transactions.
+///
+/// For more details see https://stripe.com/docs/api#balance_history.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BalanceTransactionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_on: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub expand: Vec<&'a str>, // e.g. "data.source"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<BalanceTransactionType>,
+}
+
+impl BalanceTransaction {
+    /// Retrieves the details of a balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api#balance_transaction_retrieve.
+    pub fn retrieve(client: &Client, transaction_id: &str) -> Result<BalanceTransaction, Error> {
+        client.get(&format!("/balance/history/{}", transaction_id))
+    }
+
+    /// Lists the balance transactions which have contributed to the account balance.
+    ///
+    /// For more details see https://stripe.com/docs/api#balance_history.
+    pub fn list(
+        client: &Client,
+        params: BalanceTransactionListParams,
+    ) -> Result<List<BalanceTransaction>, Error> {
+        client.get(&format!("/balance/history?{}", qs::to_string(&params)?))
+    }
+}
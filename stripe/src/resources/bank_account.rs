@@ -7,19 +7,19 @@ use resources::Currency;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BankAccount {
     pub id: String,
-    pub object: String,
-    pub account: String,
-    pub account_holder_name: String,
-    pub account_holder_type: String, // (individual or company)
-    pub bank_name: String,
+    pub account: Option<String>,
+    pub account_holder_name: Option<String>,
+    pub account_holder_type: Option<String>, // (individual or company)
+    pub bank_name: Option<String>,
     pub country: String,
     pub currency: Currency,
-    pub customer: String,
-    pub default_for_currency: bool,
-    pub fingerprint: String,
+    pub customer: Option<String>,
+    pub default_for_currency: Option<bool>,
+    pub fingerprint: Option<String>,
     pub last4: String,
+    #[serde(default)]
     pub metadata: Metadata,
-    pub routing_number: String,
+    pub routing_number: Option<String>,
     pub status: String, // (new, validated, verified, verification_failed, errored)
 }
 
@@ -1,7 +1,10 @@
 use client::Client;
 use error::{Error, ErrorCode};
-use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
-use resources::{Address, Currency, PaymentSource, PaymentSourceParams, Refund};
+use ids::{CustomerId, PaymentIntentId};
+use params::{Expandable, Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::{
+    Address, Currency, PaymentSource, PaymentSourceParams, Refund, RefundParams, RefundReason, Review,
+};
 use serde_qs as qs;
 
 /// The resource representing a Stripe charge object outcome.
@@ -247,17 +250,32 @@ pub struct ChargeListParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<RangeQuery<Timestamp>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer: Option<&'a str>,
+    pub customer: Option<CustomerId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ending_before: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<PaymentIntentId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<SourceFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_after: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transfer_group: Option<&'a str>,
+    pub transfer_group: Option<&'a str>, // (an arbitrary string, not an id)
+}
+
+/// The set of parameters that can be used when listing the refunds of a charge.
+///
+/// For more details see [https://stripe.com/docs/api/refunds/list](https://stripe.com/docs/api/refunds/list).
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ChargeRefundListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
 }
 
 /// The resource representing a Stripe charge object.
@@ -293,7 +311,7 @@ pub struct Charge {
     pub receipt_number: Option<String>,
     pub refunded: bool,
     pub refunds: List<Refund>,
-    pub review: Option<String>,
+    pub review: Option<Expandable<Review>>,
     pub shipping: Option<ShippingDetails>,
     pub source: PaymentSource,
     pub source_transfer: Option<String>,
@@ -354,10 +372,43 @@ impl Charge {
         client.post(&format!("/charges/{}/capture", charge_id), params)
     }
 
+    /// Refunds all of a charge, or `amount` of it if given.
+    ///
+    /// The returned refund's `charge` is expanded, and that charge's `amount_refunded`
+    /// and `refunds` already include the new refund.
+    ///
+    /// For more details see [https://stripe.com/docs/api/refunds/create](https://stripe.com/docs/api/refunds/create).
+    pub fn refund(
+        client: &Client,
+        charge_id: &str,
+        amount: Option<u64>,
+        reason: Option<RefundReason>,
+    ) -> Result<Refund, Error> {
+        let params = RefundParams {
+            charge: Some(charge_id.to_string()),
+            amount,
+            expand: vec!["charge".to_string()],
+            reason,
+            ..Default::default()
+        };
+        Refund::create(client, params)
+    }
+
+    /// List the refunds of a charge.
+    ///
+    /// For more details see [https://stripe.com/docs/api/refunds/list](https://stripe.com/docs/api/refunds/list).
+    pub fn list_refunds(
+        client: &Client,
+        charge_id: &str,
+        params: ChargeRefundListParams,
+    ) -> Result<List<Refund>, Error> {
+        client.get(&format!("/charges/{}/refunds?{}", charge_id, qs::to_string(&params)?))
+    }
+
     /// List all charges.
     ///
     /// For more details see [https://stripe.com/docs/api#list_charges](https://stripe.com/docs/api#list_charges).
-    pub fn list(client: &Client, params: ChargeListParams) -> Result<Vec<Charge>, Error> {
+    pub fn list(client: &Client, params: ChargeListParams) -> Result<List<Charge>, Error> {
         client.get(&format!("/charges?{}", qs::to_string(&params)?))
     }
 }
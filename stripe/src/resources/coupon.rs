@@ -1,5 +1,21 @@
-use params::{Identifiable, Metadata, Timestamp};
-use resources::Currency;
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::{Currency, Deleted};
+use serde_qs as qs;
+
+/// An enum representing the possible values of a `Coupon`'s `duration` field.
+///
+/// For more details see https://stripe.com/docs/api#coupon_object-duration.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CouponDuration {
+    Forever,
+    Once,
+    Repeating,
+    #[serde(other)]
+    Other,
+}
 
 /// The resource representing a Stripe coupon.
 ///
@@ -11,15 +27,17 @@ pub struct Coupon {
     pub amount_off: Option<u64>,
     pub created: Timestamp,
     pub currency: Option<Currency>,
-    pub duration: String, // (forever, once, repeating)
+    pub duration: CouponDuration,
     pub duration_in_months: Option<u64>,
     pub livemode: bool,
     pub max_redemptions: Option<u64>,
     pub metadata: Metadata,
-    pub percent_off: u64, // eg. 50 => 50%
-    pub redeem_by: Timestamp,
-    pub redeemed: u64,
+    pub name: Option<String>,
+    pub percent_off: Option<f64>, // eg. 50 => 50%
+    pub redeem_by: Option<Timestamp>,
+    pub times_redeemed: u64,
     pub valid: bool,
+    #[serde(default)]
     pub deleted: bool,
 }
 
@@ -29,3 +47,133 @@ impl Identifiable for Coupon {
     }
 }
 
+/// The discount applied by a coupon, which is either a percentage or a fixed amount.
+///
+/// Stripe requires exactly one of `percent_off` or `amount_off` (with a `currency`)
+/// when creating a coupon, so they are combined into a single enum.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum CouponValue {
+    PercentOff { percent_off: f64 },
+    AmountOff { amount_off: u64, currency: Currency },
+}
+
+/// The set of parameters that can be used when creating a coupon.
+///
+/// For more details see https://stripe.com/docs/api#create_coupon.
+#[derive(Clone, Debug, Serialize)]
+pub struct CouponParams<'a> {
+    pub duration: CouponDuration,
+    #[serde(flatten)]
+    pub value: CouponValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_in_months: Option<u64>, // (required if `duration` is `repeating`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_redemptions: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redeem_by: Option<Timestamp>,
+}
+
+impl<'a> CouponParams<'a> {
+    /// Creates the parameters for a coupon which takes a percentage off.
+    pub fn percent_off(duration: CouponDuration, percent_off: f64) -> CouponParams<'a> {
+        CouponParams::new(duration, CouponValue::PercentOff { percent_off })
+    }
+
+    /// Creates the parameters for a coupon which takes a fixed amount off.
+    pub fn amount_off(
+        duration: CouponDuration,
+        amount_off: u64,
+        currency: Currency,
+    ) -> CouponParams<'a> {
+        CouponParams::new(duration, CouponValue::AmountOff { amount_off, currency })
+    }
+
+    fn new(duration: CouponDuration, value: CouponValue) -> CouponParams<'a> {
+        CouponParams {
+            duration,
+            value,
+            duration_in_months: None,
+            id: None,
+            max_redemptions: None,
+            metadata: None,
+            name: None,
+            redeem_by: None,
+        }
+    }
+}
+
+/// The set of parameters that can be used when updating a coupon.
+///
+/// For more details see https://stripe.com/docs/api#update_coupon.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CouponUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when listing coupons.
+///
+/// For more details see https://stripe.com/docs/api#list_coupons.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CouponListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl Coupon {
+    /// Creates a new coupon.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_coupon.
+    pub fn create(client: &Client, params: CouponParams) -> Result<Coupon, Error> {
+        client.post("/coupons", params)
+    }
+
+    /// Retrieves the details of a coupon.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_coupon.
+    pub fn retrieve(client: &Client, coupon_id: &str) -> Result<Coupon, Error> {
+        client.get(&format!("/coupons/{}", coupon_id))
+    }
+
+    /// Updates a coupon's metadata or name.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_coupon.
+    pub fn update(
+        client: &Client,
+        coupon_id: &str,
+        params: CouponUpdateParams,
+    ) -> Result<Coupon, Error> {
+        client.post(&format!("/coupons/{}", coupon_id), params)
+    }
+
+    /// Deletes a coupon.
+    ///
+    /// Customers who already have the coupon applied keep their discount.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_coupon.
+    pub fn delete(client: &Client, coupon_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/coupons/{}", coupon_id))
+    }
+
+    /// Lists all coupons.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_coupons.
+    pub fn list(client: &Client, params: CouponListParams) -> Result<List<Coupon>, Error> {
+        client.get(&format!("/coupons?{}", qs::to_string(&params)?))
+    }
+}
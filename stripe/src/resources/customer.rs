@@ -2,7 +2,7 @@ use client::Client;
 use error::Error;
 use ids::PaymentSourceId;
 use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
-use resources::{Address, Currency, Deleted, Discount, PaymentSource, PaymentSourceParams, Subscription};
+use resources::{Address, Currency, Deleted, DeletedDiscount, Discount, PaymentSource, PaymentSourceParams, Subscription};
 use serde_qs as qs;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -107,6 +107,13 @@ impl Customer {
         client.delete(&format!("/customers/{}", customer_id))
     }
 
+    /// Removes the discount currently applied to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_discount.
+    pub fn delete_discount(client: &Client, customer_id: &str) -> Result<DeletedDiscount, Error> {
+        client.delete(&format!("/customers/{}/discount", customer_id))
+    }
+
     /// List customers.
     ///
     /// For more details see https://stripe.com/docs/api#list_customers.
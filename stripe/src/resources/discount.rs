@@ -13,3 +13,11 @@ pub struct Discount {
     pub start: Timestamp,
     pub end: Option<Timestamp>,
 }
+
+/// The response from removing a discount from a customer or subscription.
+///
+/// For more details see https://stripe.com/docs/api#delete_discount.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeletedDiscount {
+    pub deleted: bool,
+}
@@ -1,5 +1,8 @@
-use params::{Identifiable, Metadata, Timestamp};
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
 use resources::{BalanceTransaction, Currency};
+use serde_qs as qs;
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct EvidenceDetails {
@@ -88,8 +91,8 @@ pub struct Dispute {
     pub is_charge_refundable: bool,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub reason: Option<String>,
-    pub status: Option<String>,
+    pub reason: DisputeReason,
+    pub status: DisputeStatus,
 }
 
 impl Identifiable for Dispute {
@@ -97,3 +100,108 @@ impl Identifiable for Dispute {
         &self.id
     }
 }
+
+/// An enum representing the possible values of a `Dispute`'s `reason` field.
+///
+/// For more details see https://stripe.com/docs/api#dispute_object-reason.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeReason {
+    BankCannotProcess,
+    CheckReturned,
+    CreditNotProcessed,
+    CustomerInitiated,
+    DebitNotAuthorized,
+    Duplicate,
+    Fraudulent,
+    General,
+    IncorrectAccountDetails,
+    InsufficientFunds,
+    ProductNotReceived,
+    ProductUnacceptable,
+    SubscriptionCanceled,
+    Unrecognized,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of a `Dispute`'s `status` field.
+///
+/// For more details see https://stripe.com/docs/api#dispute_object-status.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    ChargeRefunded,
+    Won,
+    Lost,
+    #[serde(other)]
+    Other,
+}
+
+/// The set of parameters that can be used when updating a dispute.
+///
+/// For more details see https://stripe.com/docs/api#update_dispute.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DisputeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<DisputeEvidenceObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Whether to immediately submit the evidence to the bank.
+    ///
+    /// If `false`, the evidence is staged on the dispute and can be edited until it is submitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<bool>,
+}
+
+/// The set of parameters that can be used when listing disputes.
+///
+/// For more details see https://stripe.com/docs/api#list_disputes.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DisputeListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl Dispute {
+    /// Retrieves the details of a dispute.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_dispute.
+    pub fn retrieve(client: &Client, dispute_id: &str) -> Result<Dispute, Error> {
+        client.get(&format!("/disputes/{}", dispute_id))
+    }
+
+    /// Updates a dispute's evidence or metadata, optionally submitting the evidence.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_dispute.
+    pub fn update(client: &Client, dispute_id: &str, params: DisputeParams) -> Result<Dispute, Error> {
+        client.post(&format!("/disputes/{}", dispute_id), params)
+    }
+
+    /// Closes a dispute, accepting it as lost.
+    ///
+    /// For more details see https://stripe.com/docs/api#close_dispute.
+    pub fn close(client: &Client, dispute_id: &str) -> Result<Dispute, Error> {
+        client.post_empty(&format!("/disputes/{}/close", dispute_id))
+    }
+
+    /// Lists all disputes.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_disputes.
+    pub fn list(client: &Client, params: DisputeListParams) -> Result<List<Dispute>, Error> {
+        client.get(&format!("/disputes?{}", qs::to_string(&params)?))
+    }
+}
@@ -0,0 +1,79 @@
+use client::Client;
+use error::Error;
+use params::{Expandable, Identifiable, List, Timestamp};
+use resources::Charge;
+use serde_qs as qs;
+
+/// An enum representing the possible values of an `EarlyFraudWarning`'s `fraud_type` field.
+///
+/// For more details see https://stripe.com/docs/api/radar/early_fraud_warnings/object#early_fraud_warning_object-fraud_type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FraudType {
+    CardNeverReceived,
+    FraudulentCardApplication,
+    MadeWithCounterfeitCard,
+    MadeWithLostCard,
+    MadeWithStolenCard,
+    Misc,
+    UnauthorizedUseOfCard,
+    #[serde(other)]
+    Other,
+}
+
+/// The resource representing a Stripe Radar early fraud warning.
+///
+/// Card issuers send these when they suspect a charge was fraudulent, often before a dispute is filed.
+///
+/// For more details see https://stripe.com/docs/api/radar/early_fraud_warnings/object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EarlyFraudWarning {
+    pub id: String,
+    pub actionable: bool, // (false once the charge is refunded or disputed)
+    pub charge: Expandable<Charge>,
+    pub created: Timestamp,
+    pub fraud_type: FraudType,
+    pub livemode: bool,
+}
+
+impl Identifiable for EarlyFraudWarning {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The set of parameters that can be used when listing early fraud warnings.
+///
+/// For more details see https://stripe.com/docs/api/radar/early_fraud_warnings/list.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EarlyFraudWarningListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub expand: Vec<&'a str>, // e.g. "data.charge"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl EarlyFraudWarning {
+    /// Retrieves the details of an early fraud warning.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/early_fraud_warnings/retrieve.
+    pub fn retrieve(client: &Client, warning_id: &str) -> Result<EarlyFraudWarning, Error> {
+        client.get(&format!("/radar/early_fraud_warnings/{}", warning_id))
+    }
+
+    /// Lists early fraud warnings, optionally only those of a single charge.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/early_fraud_warnings/list.
+    pub fn list(
+        client: &Client,
+        params: EarlyFraudWarningListParams,
+    ) -> Result<List<EarlyFraudWarning>, Error> {
+        client.get(&format!("/radar/early_fraud_warnings?{}", qs::to_string(&params)?))
+    }
+}
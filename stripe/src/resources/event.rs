@@ -110,6 +110,12 @@ pub enum EventType {
     OrderUpdated,
     #[serde(rename = "order_return.updated")]
     OrderReturnUpdated,
+    #[serde(rename = "payment_method.attached")]
+    PaymentMethodAttached,
+    #[serde(rename = "payment_method.detached")]
+    PaymentMethodDetached,
+    #[serde(rename = "payment_method.updated")]
+    PaymentMethodUpdated,
     #[serde(rename = "payout.canceled")]
     PayoutCanceled,
     #[serde(rename = "payout.created")]
@@ -132,6 +138,10 @@ pub enum EventType {
     ProductDeleted,
     #[serde(rename = "product.updated")]
     ProductUpdated,
+    #[serde(rename = "radar.early_fraud_warning.created")]
+    RadarEarlyFraudWarningCreated,
+    #[serde(rename = "radar.early_fraud_warning.updated")]
+    RadarEarlyFraudWarningUpdated,
     #[serde(rename = "review.closed")]
     ReviewClosed,
     #[serde(rename = "review.opened")]
@@ -185,11 +195,14 @@ pub enum EventObject {
     BankAccount(BankAccount),
     Charge(Charge),
     Dispute(Dispute),
+    #[serde(rename = "radar.early_fraud_warning")]
+    EarlyFraudWarning(EarlyFraudWarning),
     File(File),
     Invoice(Invoice),
     InvoiceItem(InvoiceItem),
     Order(Order),
     OrderReturn(OrderReturn),
+    PaymentMethod(PaymentMethod),
     Payout(Payout),
     Plan(Plan),
     Product(Product),
@@ -211,27 +224,36 @@ impl Webhook {
         sig: String,
         secret: String,
     ) -> Result<Event, WebhookError> {
-        let mut headers: Vec<String> = sig.split(",").map(|s| s.trim().to_string()).collect();
+        // Read the timestamp and signatures from the header, which may contain
+        // more than one `v1` signature while an endpoint secret is being rolled.
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+        for pair in sig.split(",") {
+            let mut pair = pair.trim().splitn(2, "=");
+            match (pair.next(), pair.next()) {
+                (Some("t"), Some(value)) => timestamp = Some(value),
+                (Some("v1"), Some(value)) => signatures.push(value),
+                _ => {}
+            }
+        }
+        let timestamp = timestamp.ok_or(WebhookError::BadSignature)?;
 
         // Prepare the signed payload
-        let ref mut timestamp: Vec<String> = headers[0].split("=").map(|s| s.to_string()).collect();
-        let signed_payload = format!("{}{}{}", timestamp[1], ".", payload);
-
-        // Get Stripe signature from header
-        let ref mut signature: Vec<String> = headers[1].split("=").map(|s| s.to_string()).collect();
+        let signed_payload = format!("{}{}{}", timestamp, ".", payload);
 
         // Compute HMAC with the SHA256 hash function, using endpoing secret as key
         // and signed_payload string as the message.
         let mut mac = Hmac::<Sha256>::new_varkey(secret.as_bytes())
             .map_err(|_| WebhookError::BadKey)?;
         mac.input(signed_payload.as_bytes());
-        if !mac.result().is_equal(signature[1].as_bytes()) {
+        let expected = mac.result();
+        if !signatures.iter().filter_map(|s| decode_hex(s)).any(|s| expected.is_equal(&s)) {
             return Err(WebhookError::BadSignature);
         }
 
         // Get current timestamp to compare to signature timestamp
         let current = Utc::now().timestamp();
-        let num_timestamp = timestamp[1]
+        let num_timestamp = timestamp
             .parse::<i64>()
             .map_err(|err| WebhookError::BadHeader(err))?;
         if current - num_timestamp > 300 {
@@ -242,3 +264,110 @@ impl Webhook {
         return json::from_str(&payload).map_err(|err| WebhookError::BadParse(err));
     }
 }
+
+/// Decodes the hex-encoded signatures found in the `Stripe-Signature` header.
+#[cfg(feature = "webhooks")]
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            str::from_utf8(pair)
+                .ok()
+                .filter(|pair| pair.len() == 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "webhooks"))]
+mod tests {
+    use chrono::Utc;
+    use error::WebhookError;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+    use super::{EventType, Webhook};
+
+    const SECRET: &str = "whsec_test_secret";
+    const PAYLOAD: &str = r#"{
+        "id": "evt_1",
+        "object": "event",
+        "type": "plan.updated",
+        "data": {
+            "object": {
+                "id": "silver",
+                "object": "plan",
+                "active": true,
+                "aggregate_usage": null,
+                "amount": 1000,
+                "billing_scheme": "per_unit",
+                "created": 1541000000,
+                "currency": "usd",
+                "interval": "month",
+                "interval_count": 1,
+                "livemode": false,
+                "metadata": {},
+                "nickname": "Silver",
+                "product": "prod_1",
+                "tiers_mode": null,
+                "transform_usage": null,
+                "trial_period_days": 14,
+                "usage_type": "licensed"
+            }
+        }
+    }"#;
+
+    fn signature(timestamp: i64, payload: &str, secret: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_varkey(secret.as_bytes()).unwrap();
+        mac.input(format!("{}.{}", timestamp, payload).as_bytes());
+        mac.result().code().iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    fn construct_event(payload: &str, header: String) -> Result<EventType, WebhookError> {
+        Webhook::construct_event(payload.to_string(), header, SECRET.to_string())
+            .map(|event| event.event_type)
+    }
+
+    #[test]
+    fn accept_valid_signature() {
+        let now = Utc::now().timestamp();
+        let header = format!("t={},v1={}", now, signature(now, PAYLOAD, SECRET));
+        assert_eq!(construct_event(PAYLOAD, header).unwrap(), EventType::PlanUpdated);
+    }
+
+    #[test]
+    fn reject_tampered_payload() {
+        let now = Utc::now().timestamp();
+        let header = format!("t={},v1={}", now, signature(now, PAYLOAD, SECRET));
+        let tampered = PAYLOAD.replace("evt_1", "evt_2");
+        match construct_event(&tampered, header) {
+            Err(WebhookError::BadSignature) => {}
+            other => panic!("expected a bad signature; was {:?}", other),
+        }
+    }
+
+    #[test]
+    fn accept_any_matching_signature() {
+        // While an endpoint secret is being rolled, the header holds one signature per secret
+        let now = Utc::now().timestamp();
+        let header = format!(
+            "t={},v1={},v1={},v0={}",
+            now,
+            signature(now, PAYLOAD, "whsec_old_secret"),
+            signature(now, PAYLOAD, SECRET),
+            signature(now, PAYLOAD, "whsec_test_mode"),
+        );
+        assert_eq!(construct_event(PAYLOAD, header).unwrap(), EventType::PlanUpdated);
+    }
+
+    #[test]
+    fn reject_non_hex_signature() {
+        let now = Utc::now().timestamp();
+        let valid = signature(now, PAYLOAD, SECRET);
+        for sig in &["zz".repeat(32), valid[1..].to_string(), format!("{}é", &valid[..62])] {
+            match construct_event(PAYLOAD, format!("t={},v1={}", now, sig)) {
+                Err(WebhookError::BadSignature) => {}
+                other => panic!("expected a bad signature for {:?}; was {:?}", sig, other),
+            }
+        }
+    }
+}
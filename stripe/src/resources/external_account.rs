@@ -0,0 +1,136 @@
+use client::Client;
+use error::Error;
+use ids::{AccountId, ExternalAccountTokenId};
+use params::{Identifiable, List, Metadata};
+use resources::{BankAccount, Card, Deleted};
+use serde_qs as qs;
+
+/// A bank account or debit card which a connected account can be paid out to.
+///
+/// For more details see https://stripe.com/docs/api#external_accounts.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum ExternalAccount {
+    BankAccount(BankAccount),
+    Card(Card),
+}
+
+impl Identifiable for ExternalAccount {
+    fn id(&self) -> &str {
+        match *self {
+            ExternalAccount::BankAccount(ref account) => account.id(),
+            ExternalAccount::Card(ref card) => card.id(),
+        }
+    }
+}
+
+/// An enum representing the kinds of external account which can be listed.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalAccountType {
+    BankAccount,
+    Card,
+}
+
+/// The set of parameters that can be used when adding an external account.
+///
+/// For more details see https://stripe.com/docs/api#account_create_bank_account.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ExternalAccountParams {
+    /// A bank account (`btok_`) or debit card (`tok_`) token, e.g. created with Stripe.js.
+    pub external_account: ExternalAccountTokenId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when updating an external account.
+///
+/// For more details see https://stripe.com/docs/api#account_update_bank_account.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ExternalAccountUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing external accounts.
+///
+/// For more details see https://stripe.com/docs/api#account_list_bank_accounts.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ExternalAccountListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<ExternalAccountType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl ExternalAccount {
+    /// Adds a bank account or debit card to a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_create_bank_account.
+    pub fn create(
+        client: &Client,
+        account_id: &AccountId,
+        params: ExternalAccountParams,
+    ) -> Result<ExternalAccount, Error> {
+        client.post(&format!("/accounts/{}/external_accounts", account_id), params)
+    }
+
+    /// Retrieves the details of an external account.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_retrieve_bank_account.
+    pub fn retrieve(
+        client: &Client,
+        account_id: &AccountId,
+        external_account_id: &str,
+    ) -> Result<ExternalAccount, Error> {
+        client.get(&format!("/accounts/{}/external_accounts/{}", account_id, external_account_id))
+    }
+
+    /// Updates an external account's properties, e.g. making it the default for its currency.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_update_bank_account.
+    pub fn update(
+        client: &Client,
+        account_id: &AccountId,
+        external_account_id: &str,
+        params: ExternalAccountUpdateParams,
+    ) -> Result<ExternalAccount, Error> {
+        let path = format!("/accounts/{}/external_accounts/{}", account_id, external_account_id);
+        client.post(&path, params)
+    }
+
+    /// Deletes an external account.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_delete_bank_account.
+    pub fn delete(
+        client: &Client,
+        account_id: &AccountId,
+        external_account_id: &str,
+    ) -> Result<Deleted, Error> {
+        client.delete(&format!("/accounts/{}/external_accounts/{}", account_id, external_account_id))
+    }
+
+    /// Lists the external accounts of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_list_bank_accounts.
+    pub fn list(
+        client: &Client,
+        account_id: &AccountId,
+        params: ExternalAccountListParams,
+    ) -> Result<List<ExternalAccount>, Error> {
+        let query = qs::to_string(&params)?;
+        client.get(&format!("/accounts/{}/external_accounts?{}", account_id, query))
+    }
+}
@@ -1,4 +1,34 @@
-use params::{Identifiable, Timestamp};
+use client::Client;
+use error::Error;
+use params::{to_snakecase, Identifiable, List, Metadata, RangeQuery, Timestamp};
+use reqwest::multipart::{Form, Part};
+use serde_qs as qs;
+use std::io::Read;
+
+/// An enum representing the possible values of a `File`'s `purpose` field.
+///
+/// For more details see https://stripe.com/docs/api#file_object-purpose.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FilePurpose {
+    BusinessIcon,
+    BusinessLogo,
+    CustomerSignature,
+    DisputeEvidence,
+    FinanceReportRun,
+    IdentityDocument,
+    PciDocument,
+    SigmaScheduledQuery,
+    TaxDocumentUserUpload,
+    #[serde(other)]
+    Other,
+}
+
+impl ::std::fmt::Display for FilePurpose {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+    }
+}
 
 /// The resource representing a Stripe file.
 ///
@@ -8,12 +38,14 @@ pub struct File {
     pub id: String,
     pub object: String,
     pub created: Timestamp,
-    pub purpose: String,
+    #[serde(default)]
+    pub filename: Option<String>,
+    pub purpose: FilePurpose,
     pub size: u64,
     pub title: Option<String>,
     #[serde(rename = "type")]
-    pub file_type: String, // (csv, pdf, jpg, png)
-    pub url: String,
+    pub file_type: Option<String>, // (csv, pdf, jpg, png)
+    pub url: Option<String>,
 }
 
 impl Identifiable for File {
@@ -21,3 +53,145 @@ impl Identifiable for File {
         &self.id
     }
 }
+
+/// The set of parameters that can be used when listing files.
+///
+/// For more details see https://stripe.com/docs/api#list_files.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FileListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<FilePurpose>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl File {
+    /// Uploads a file to Stripe.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_file.
+    pub fn create(
+        client: &Client,
+        purpose: FilePurpose,
+        filename: &str,
+        contents: Vec<u8>,
+    ) -> Result<File, Error> {
+        let form = File::upload_form(purpose, filename, Part::bytes(contents));
+        client.post_multipart("/files", form)
+    }
+
+    /// Uploads a file to Stripe, streaming its contents from a reader.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_file.
+    pub fn create_from_reader<R: Read + Send + 'static>(
+        client: &Client,
+        purpose: FilePurpose,
+        filename: &str,
+        reader: R,
+    ) -> Result<File, Error> {
+        let form = File::upload_form(purpose, filename, Part::reader(reader));
+        client.post_multipart("/files", form)
+    }
+
+    /// Builds the `multipart/form-data` body which uploads `file` with the given purpose and name.
+    pub fn upload_form(purpose: FilePurpose, filename: &str, file: Part) -> Form {
+        Form::new()
+            .text("purpose", purpose.to_string())
+            .part("file", file.file_name(filename.to_string()))
+    }
+
+    /// Retrieves the details of a file.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_file.
+    pub fn retrieve(client: &Client, file_id: &str) -> Result<File, Error> {
+        client.get(&format!("/files/{}", file_id))
+    }
+
+    /// Lists the files uploaded to Stripe.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_files.
+    pub fn list(client: &Client, params: FileListParams) -> Result<List<File>, Error> {
+        client.get(&format!("/files?{}", qs::to_string(&params)?))
+    }
+}
+
+/// The resource representing a Stripe file link.
+///
+/// For more details see https://stripe.com/docs/api#file_link_object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileLink {
+    pub id: String,
+    pub object: String,
+    pub created: Timestamp,
+    pub expired: bool,
+    pub expires_at: Option<Timestamp>,
+    pub file: String,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub url: Option<String>,
+}
+
+impl Identifiable for FileLink {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The set of parameters that can be used when creating a file link.
+///
+/// For more details see https://stripe.com/docs/api#create_file_link.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FileLinkParams<'a> {
+    pub file: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing file links.
+///
+/// For more details see https://stripe.com/docs/api#list_file_links.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FileLinkListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl FileLink {
+    /// Creates a publicly accessible link to a file.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_file_link.
+    pub fn create(client: &Client, params: FileLinkParams) -> Result<FileLink, Error> {
+        client.post("/file_links", params)
+    }
+
+    /// Retrieves the details of a file link.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_file_link.
+    pub fn retrieve(client: &Client, file_link_id: &str) -> Result<FileLink, Error> {
+        client.get(&format!("/file_links/{}", file_link_id))
+    }
+
+    /// Lists file links.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_file_links.
+    pub fn list(client: &Client, params: FileLinkListParams) -> Result<List<FileLink>, Error> {
+        client.get(&format!("/file_links?{}", qs::to_string(&params)?))
+    }
+}
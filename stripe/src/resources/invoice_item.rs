@@ -1,5 +1,8 @@
-use params::{Metadata, Timestamp};
-use resources::{Currency, Period, Plan};
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::{Currency, Deleted, Period, Plan};
+use serde_qs as qs;
 
 /// The resource representing a Stripe invoice item.
 ///
@@ -8,13 +11,13 @@ use resources::{Currency, Period, Plan};
 pub struct InvoiceItem {
     pub id: String,
     pub object: String,
-    pub amount: u64,
+    pub amount: i64,
     pub currency: Currency,
     pub customer: String,
     pub date: Timestamp,
-    pub description: String,
-    pub discountable: String,
-    pub invoice: String,
+    pub description: Option<String>,
+    pub discountable: bool,
+    pub invoice: Option<String>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub period: Period,
@@ -23,4 +26,131 @@ pub struct InvoiceItem {
     pub quantity: u64,
     pub subscription: Option<String>,
     pub subscription_item: Option<String>,
+    pub unit_amount: Option<i64>,
+}
+
+impl Identifiable for InvoiceItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The set of parameters that can be used when creating an invoice item.
+///
+/// Either `amount` or `unit_amount` (with an optional `quantity`) should be set.
+///
+/// For more details see https://stripe.com/docs/api#create_invoiceitem.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceItemParams<'a> {
+    pub customer: &'a str,
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discountable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<Period>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tax_rates: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+}
+
+/// The set of parameters that can be used when updating an invoice item.
+///
+/// For more details see https://stripe.com/docs/api#update_invoiceitem.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceItemUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discountable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<Period>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tax_rates: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+}
+
+/// The set of parameters that can be used when listing invoice items.
+///
+/// For more details see https://stripe.com/docs/api#list_invoiceitems.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceItemListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending: Option<bool>, // (only items not yet attached to an invoice)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl InvoiceItem {
+    /// Creates an invoice item, which is added to the customer's next invoice
+    /// unless an `invoice` is given.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_invoiceitem.
+    pub fn create(client: &Client, params: InvoiceItemParams) -> Result<InvoiceItem, Error> {
+        client.post("/invoiceitems", params)
+    }
+
+    /// Retrieves the details of an invoice item.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_invoiceitem.
+    pub fn retrieve(client: &Client, invoice_item_id: &str) -> Result<InvoiceItem, Error> {
+        client.get(&format!("/invoiceitems/{}", invoice_item_id))
+    }
+
+    /// Updates an invoice item, as long as its invoice has not been finalized.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_invoiceitem.
+    pub fn update(
+        client: &Client,
+        invoice_item_id: &str,
+        params: InvoiceItemUpdateParams,
+    ) -> Result<InvoiceItem, Error> {
+        client.post(&format!("/invoiceitems/{}", invoice_item_id), params)
+    }
+
+    /// Deletes an invoice item, as long as its invoice has not been finalized.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_invoiceitem.
+    pub fn delete(client: &Client, invoice_item_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/invoiceitems/{}", invoice_item_id))
+    }
+
+    /// Lists all invoice items.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_invoiceitems.
+    pub fn list(
+        client: &Client,
+        params: InvoiceItemListParams,
+    ) -> Result<List<InvoiceItem>, Error> {
+        client.get(&format!("/invoiceitems?{}", qs::to_string(&params)?))
+    }
 }
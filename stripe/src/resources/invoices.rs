@@ -1,9 +1,37 @@
 use client::Client;
 use error::Error;
+use ids::PaymentSourceId;
 use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
-use resources::{Currency, Discount, Plan};
+use resources::{BillingCycleAnchor, Currency, Discount, Plan};
 use serde_qs as qs;
 
+/// An enum representing the possible values of an `Invoice`'s `status` field.
+///
+/// For more details see https://stripe.com/docs/api#invoice_object-status.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    Draft,
+    Open,
+    Paid,
+    Uncollectible,
+    Void,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of an `Invoice`'s `billing` field.
+///
+/// For more details see https://stripe.com/docs/api#invoice_object-billing.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceBilling {
+    ChargeAutomatically,
+    SendInvoice,
+    #[serde(other)]
+    Other,
+}
+
 /// The set of parameters that can be used when creating or updating an invoice.
 ///
 /// For more details see https://stripe.com/docs/api#create_invoice, https://stripe.com/docs/api#update_invoice.
@@ -12,10 +40,20 @@ pub struct InvoiceParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing: Option<InvoiceBilling>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u64>, // (only for `send_invoice` billing)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Timestamp>, // (only for `send_invoice` billing)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription: Option<&'a str>,
@@ -28,37 +66,47 @@ pub struct InvoiceParams<'a> {
     pub forgiven: Option<bool>,
 }
 
+/// The set of parameters that can be used when paying an invoice.
+///
+/// For more details see https://stripe.com/docs/api#pay_invoice.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct InvoiceLineItemParams<'a> {
+pub struct InvoicePayParams {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<i64>,
+    pub forgive: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<Currency>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer: Option<&'a str>,
+    pub paid_out_of_band: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'a str>,
+    pub source: Option<PaymentSourceId>,
+}
+
+/// The set of parameters that can be used when finalizing a draft invoice.
+///
+/// For more details see https://stripe.com/docs/api#finalize_invoice.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceFinalizeParams {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub discountable: Option<bool>,
+    pub auto_advance: Option<bool>,
+}
+
+/// The set of parameters that can be used when listing an invoice's line items.
+///
+/// For more details see https://stripe.com/docs/api#invoice_lines.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceListLinesParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invoice: Option<&'a str>,
+    pub ending_before: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<bool>,
+    pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription: Option<bool>,
-}
-
-/*
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InvoiceListLinesParams {
-    #[serde(skip_serializing_if = "Option::is_none")] pub limit: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub ending_before: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub starting_after: Option<String>,
-
-    ..
+    pub starting_after: Option<&'a str>,
 }
-*/
 
+/// The set of parameters that can be used when previewing an upcoming invoice.
+///
+/// The `subscription_*` parameters simulate changes to a subscription (or a new
+/// subscription) so that the resulting prorations can be previewed.
+///
+/// For more details see https://stripe.com/docs/api#upcoming_invoice.
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct InvoiceUpcomingParams<'a> {
     pub customer: &'a str, // this is a required param
@@ -67,22 +115,44 @@ pub struct InvoiceUpcomingParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_items: Option<SubscriptionItemParams<'a>>,
+    pub subscription_billing_cycle_anchor: Option<BillingCycleAnchor>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub subscription_items: Vec<SubscriptionItemParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_plan: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_prorate: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_proration_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_tax_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_trial_end: Option<Timestamp>,
 }
 
-#[derive(Clone, Debug, Serialize)]
+/// The set of parameters that can be used when listing the line items of an upcoming invoice.
+///
+/// For more details see https://stripe.com/docs/api#upcoming_invoice_lines.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct InvoiceUpcomingLinesParams<'a> {
+    #[serde(flatten)]
+    pub upcoming: InvoiceUpcomingParams<'a>,
+    #[serde(flatten)]
+    pub lines: InvoiceListLinesParams<'a>,
+}
+
+/// A subscription item to add, update or remove when previewing an upcoming invoice.
+///
+/// For more details see https://stripe.com/docs/api#upcoming_invoice-subscription_items.
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct SubscriptionItemParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_usage: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
@@ -117,8 +187,6 @@ pub struct InvoiceLineItem {
     pub quantity: Option<u64>,
     pub subscription: Option<String>,
     pub subscription_item: Option<String>,
-    #[serde(default)]
-    // NOTE: Missing in response to InvoiceLineItem create
     #[serde(rename = "type")]
     pub item_type: String, // (invoiceitem, subscription)
 }
@@ -139,25 +207,32 @@ pub struct Invoice {
     pub application_fee: Option<u64>,
     pub attempt_count: u64,
     pub attempted: bool,
+    pub auto_advance: Option<bool>,
+    pub billing: Option<InvoiceBilling>,
     pub charge: Option<String>,
-    pub closed: bool,
+    #[serde(default)]
+    pub closed: bool, // (removed in API version 2018-11-08, see `status`)
     pub currency: Currency,
     pub customer: String,
     pub date: Timestamp,
     pub description: Option<String>,
     pub discount: Option<Discount>,
+    pub due_date: Option<Timestamp>,
     pub ending_balance: Option<i64>,
-    pub forgiven: bool,
+    #[serde(default)]
+    pub forgiven: bool, // (removed in API version 2018-11-08, see `status`)
     pub lines: List<InvoiceLineItem>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub next_payment_attempt: Option<Timestamp>,
+    pub number: Option<String>,
     pub paid: bool,
     pub period_end: Timestamp,
     pub period_start: Timestamp,
     pub receipt_number: Option<String>,
     pub starting_balance: i64,
-    pub statment_descriptor: Option<String>,
+    pub statement_descriptor: Option<String>,
+    pub status: Option<InvoiceStatus>,
     pub subscription: Option<String>,
     pub subscription_proration_date: Option<Timestamp>,
     pub subtotal: i64,
@@ -176,10 +251,12 @@ pub struct InvoiceListParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ending_before: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<u64>,
+    pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_after: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InvoiceStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription: Option<&'a str>,
 }
 
@@ -198,10 +275,18 @@ impl Invoice {
         client.get(&format!("/invoices/{}", invoice_id))
     }
 
-    // TODO: Implement InvoiceListLinesParams
-    // pub fn get_lines(client: &Client, invoice_id: &str, params: InvoiceListLinesParams) -> Result<List<InvoiceLineItem>, Error> {
-    //     client.get(&format!("/invoices/{}/lines", invoice_id))
-    // }
+    /// Lists the line items of an invoice.
+    ///
+    /// The returned list can be paged through with `List::next` and `List::get_all`.
+    ///
+    /// For more details see https://stripe.com/docs/api#invoice_lines.
+    pub fn lines(
+        client: &Client,
+        invoice_id: &str,
+        params: InvoiceListLinesParams,
+    ) -> Result<List<InvoiceLineItem>, Error> {
+        client.get(&format!("/invoices/{}/lines?{}", invoice_id, qs::to_string(&params)?))
+    }
 
     /// Retrieves the details of an upcoming invoice_id
     ///
@@ -210,11 +295,59 @@ impl Invoice {
         client.get(&format!("/invoices/upcoming?{}", qs::to_string(&params)?))
     }
 
+    /// Lists the line items of an upcoming invoice.
+    ///
+    /// Useful when the upcoming invoice has more line items than are included in `Invoice::upcoming`.
+    ///
+    /// For more details see https://stripe.com/docs/api#upcoming_invoice_lines.
+    pub fn upcoming_lines(
+        client: &Client,
+        params: InvoiceUpcomingLinesParams,
+    ) -> Result<List<InvoiceLineItem>, Error> {
+        client.get(&format!("/invoices/upcoming/lines?{}", qs::to_string(&params)?))
+    }
+
     /// Pays an invoice.
     ///
     /// For more details see https://stripe.com/docs/api#pay_invoice.
-    pub fn pay(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
-        client.post_empty(&format!("/invoices/{}/pay", invoice_id))
+    pub fn pay(
+        client: &Client,
+        invoice_id: &str,
+        params: InvoicePayParams,
+    ) -> Result<Invoice, Error> {
+        client.post(&format!("/invoices/{}/pay", invoice_id), params)
+    }
+
+    /// Finalizes a draft invoice so that it can be paid.
+    ///
+    /// For more details see https://stripe.com/docs/api#finalize_invoice.
+    pub fn finalize(
+        client: &Client,
+        invoice_id: &str,
+        params: InvoiceFinalizeParams,
+    ) -> Result<Invoice, Error> {
+        client.post(&format!("/invoices/{}/finalize", invoice_id), params)
+    }
+
+    /// Voids a finalized invoice.
+    ///
+    /// For more details see https://stripe.com/docs/api#void_invoice.
+    pub fn void(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post_empty(&format!("/invoices/{}/void", invoice_id))
+    }
+
+    /// Sends an invoice to the customer, for invoices with `send_invoice` billing.
+    ///
+    /// For more details see https://stripe.com/docs/api#send_invoice.
+    pub fn send(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post_empty(&format!("/invoices/{}/send", invoice_id))
+    }
+
+    /// Marks a finalized invoice as uncollectible.
+    ///
+    /// For more details see https://stripe.com/docs/api#mark_uncollectible_invoice.
+    pub fn mark_uncollectible(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post_empty(&format!("/invoices/{}/mark_uncollectible", invoice_id))
     }
 
     /// Updates an invoice.
@@ -236,18 +369,6 @@ impl Invoice {
     }
 }
 
-impl InvoiceLineItem {
-    /// Creates an invoice line item.
-    ///
-    /// For more details see https://stripe.com/docs/api#invoice_line_item_object
-    pub fn create(
-        client: &Client,
-        params: InvoiceLineItemParams,
-    ) -> Result<InvoiceLineItem, Error> {
-        client.post(&format!("/invoiceitems"), &params)
-    }
-}
-
 /// Since Invoice ID can be empty, special impl for fetching result from list
 impl List<Invoice> {
     pub fn get_all(self, client: &Client) -> Result<Vec<Invoice>, Error> {
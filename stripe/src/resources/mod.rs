@@ -12,25 +12,32 @@ mod customer;
 mod deleted;
 mod discount;
 mod dispute;
+mod early_fraud_warning;
 mod event;
+mod external_account;
 mod file;
 mod invoice_item;
 mod invoices;
+mod oauth;
 mod order;
 mod order_return;
 mod payment_source;
 mod payment_intents;
+mod payment_method;
 mod payout;
 mod plan;
 mod product;
+mod reconciliation;
 mod refund;
 mod review;
 mod scheduled_query;
+mod setup_intent;
 mod sku;
 mod source;
 mod subscription;
 mod transaction;
 mod transfer;
+mod value_list;
 
 pub use resources::account::*;
 pub use resources::address::*;
@@ -46,22 +53,29 @@ pub use resources::customer::*;
 pub use resources::deleted::*;
 pub use resources::discount::*;
 pub use resources::dispute::*;
+pub use resources::early_fraud_warning::*;
 pub use resources::event::*;
+pub use resources::external_account::*;
 pub use resources::file::*;
 pub use resources::invoice_item::*;
 pub use resources::invoices::*;
+pub use resources::oauth::*;
 pub use resources::order::*;
 pub use resources::order_return::*;
 pub use resources::payment_source::*;
 pub use resources::payment_intents::*;
+pub use resources::payment_method::*;
 pub use resources::payout::*;
 pub use resources::plan::*;
 pub use resources::product::*;
+pub use resources::reconciliation::*;
 pub use resources::refund::*;
 pub use resources::review::*;
 pub use resources::scheduled_query::*;
+pub use resources::setup_intent::*;
 pub use resources::sku::*;
 pub use resources::source::*;
 pub use resources::subscription::*;
 pub use resources::transaction::*;
 pub use resources::transfer::*;
+pub use resources::value_list::*;
@@ -0,0 +1,175 @@
+use client::Client;
+use error::Error;
+use ids::AccountId;
+use serde_qs as qs;
+
+/// An enum representing the permissions a platform can request of a connected account.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#get-authorize-request.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthScope {
+    ReadOnly,
+    ReadWrite,
+}
+
+/// Details used to prefill the account application form for a new user.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#get-authorize-request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StripeUserParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_address: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when sending a user to connect their account.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#get-authorize-request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthorizeUrlParams<'a> {
+    pub client_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<OAuthScope>,
+    /// An arbitrary value which is returned to the `redirect_uri` to protect against CSRF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_landing: Option<&'a str>, // (login or register)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub always_prompt: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_user: Option<StripeUserParams<'a>>,
+}
+
+impl<'a> AuthorizeUrlParams<'a> {
+    /// Builds the `connect.stripe.com/oauth/authorize` url to redirect the user to.
+    pub fn url(&self) -> Result<String, Error> {
+        Ok(format!(
+            "https://connect.stripe.com/oauth/authorize?response_type=code&{}",
+            qs::to_string(self)?
+        ))
+    }
+}
+
+/// An enum representing the possible values of an `OAuthTokenParams`'s `grant_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthGrantType {
+    AuthorizationCode,
+    RefreshToken,
+}
+
+/// The set of parameters that can be used when requesting an access token.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-token-request.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OAuthTokenParams<'a> {
+    pub grant_type: OAuthGrantType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<OAuthScope>,
+}
+
+/// The credentials for a connected account returned by `/oauth/token`.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-token-response.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OAuthToken {
+    pub access_token: String,
+    pub livemode: bool,
+    pub refresh_token: Option<String>,
+    pub scope: OAuthScope,
+    pub stripe_publishable_key: String,
+    pub stripe_user_id: AccountId,
+    pub token_type: String,
+}
+
+/// The response to disconnecting an account with `/oauth/deauthorize`.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-deauthorize-response.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OAuthDeauthorization {
+    pub stripe_user_id: AccountId,
+}
+
+#[derive(Serialize)]
+struct DeauthorizeParams<'a> {
+    client_id: &'a str,
+    stripe_user_id: &'a AccountId,
+}
+
+impl OAuthToken {
+    /// Requests an access token for a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/connect/oauth-reference#post-token.
+    pub fn create(client: &Client, params: OAuthTokenParams) -> Result<OAuthToken, Error> {
+        client.post_oauth("/oauth/token", params)
+    }
+
+    /// Exchanges the authorization code from the `redirect_uri` for an access token.
+    ///
+    /// For more details see https://stripe.com/docs/connect/oauth-reference#post-token.
+    pub fn authorize(client: &Client, code: &str) -> Result<OAuthToken, Error> {
+        OAuthToken::create(client, OAuthTokenParams {
+            grant_type: OAuthGrantType::AuthorizationCode,
+            code: Some(code),
+            refresh_token: None,
+            scope: None,
+        })
+    }
+
+    /// Uses a refresh token to request a new access token.
+    ///
+    /// For more details see https://stripe.com/docs/connect/oauth-reference#post-token.
+    pub fn refresh(client: &Client, refresh_token: &str) -> Result<OAuthToken, Error> {
+        OAuthToken::create(client, OAuthTokenParams {
+            grant_type: OAuthGrantType::RefreshToken,
+            code: None,
+            refresh_token: Some(refresh_token),
+            scope: None,
+        })
+    }
+
+    /// Disconnects an account from the platform.
+    ///
+    /// For more details see https://stripe.com/docs/connect/oauth-reference#post-deauthorize.
+    pub fn deauthorize(
+        client: &Client,
+        client_id: &str,
+        stripe_user_id: &AccountId,
+    ) -> Result<OAuthDeauthorization, Error> {
+        let params = DeauthorizeParams { client_id, stripe_user_id };
+        client.post_oauth("/oauth/deauthorize", params)
+    }
+}
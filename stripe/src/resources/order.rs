@@ -1,6 +1,8 @@
-use params::{Identifiable, List, Metadata, Timestamp};
-use resources::{Currency, ShippingDetails};
-use serde_json as json;
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::{Currency, OrderReturn, PaymentSourceParams, ShippingDetails};
+use serde_qs as qs;
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct StatusTransitions {
@@ -14,19 +16,82 @@ pub struct StatusTransitions {
     pub returned: Option<Timestamp>,
 }
 
+/// An enum representing the possible values of an `Order`'s `status` field.
+///
+/// For more details see https://stripe.com/docs/api#order_object-status.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    Created,
+    Paid,
+    Canceled,
+    Fulfilled,
+    Returned,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of an `OrderItem`'s `type` field.
+///
+/// For more details see https://stripe.com/docs/api#order_item_object-type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderItemType {
+    Sku,
+    Tax,
+    Shipping,
+    Discount,
+    #[serde(other)]
+    Other,
+}
+
 /// The resource representing a Stripe order item.
 ///
 /// For more details see https://stripe.com/docs/api#order_item_object.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OrderItem {
     pub object: String,
-    pub amount: u64,
+    pub amount: i64,
     pub currency: Currency,
     pub description: String,
     pub parent: Option<String>,
     pub quantity: Option<u64>,
     #[serde(rename = "type")]
-    pub item_type: String,
+    pub item_type: OrderItemType,
+}
+
+/// An enum representing the possible values of a `DeliveryEstimate`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryEstimateType {
+    Exact,
+    Range,
+    #[serde(other)]
+    Other,
+}
+
+/// The estimated delivery date of a shipping method.
+///
+/// For more details see https://stripe.com/docs/api#order_object-shipping_methods-delivery_estimate.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeliveryEstimate {
+    #[serde(rename = "type")]
+    pub estimate_type: DeliveryEstimateType,
+    pub date: Option<String>,     // (used by `exact` estimates)
+    pub earliest: Option<String>, // (used by `range` estimates)
+    pub latest: Option<String>,   // (used by `range` estimates)
+}
+
+/// A shipping method which is available for an order.
+///
+/// For more details see https://stripe.com/docs/api#order_object-shipping_methods.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShippingMethod {
+    pub id: String,
+    pub amount: u64,
+    pub currency: Currency,
+    pub delivery_estimate: Option<DeliveryEstimate>,
+    pub description: String,
 }
 
 /// The resource representing a Stripe order.
@@ -37,25 +102,26 @@ pub struct Order {
     pub id: String,
     pub object: String,
     pub amount: u64,
-    pub amount_returned: u64,
-    pub application: String,
-    pub application_fee: u64,
+    pub amount_returned: Option<u64>,
+    pub application: Option<String>,
+    pub application_fee: Option<u64>,
     pub charge: Option<String>,
     pub created: Timestamp,
     pub currency: Currency,
-    pub customer: String,
-    pub email: String,
-    pub external_coupon_code: String,
-    pub items: List<OrderItem>,
+    pub customer: Option<String>,
+    pub email: Option<String>,
+    pub external_coupon_code: Option<String>,
+    pub items: Vec<OrderItem>,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub returns: List<json::Value>,
+    pub returns: Option<List<OrderReturn>>,
     pub selected_shipping_method: Option<String>,
     pub shipping: Option<ShippingDetails>,
-    pub shipping_methods: List<json::Value>,
-    pub status: String, // (created, paid, canceled, fulfilled, returned)
+    #[serde(default)]
+    pub shipping_methods: Vec<ShippingMethod>,
+    pub status: OrderStatus,
     pub status_transitions: StatusTransitions,
-    pub udpated: Timestamp,
+    pub updated: Timestamp,
     pub upstream_id: Option<String>,
 }
 
@@ -64,3 +130,169 @@ impl Identifiable for Order {
         &self.id
     }
 }
+
+/// The set of parameters that can be used when describing an item of an order or a return.
+///
+/// For more details see https://stripe.com/docs/api#create_order-items.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OrderItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<&'a str>, // (the id of a sku)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<OrderItemType>,
+}
+
+/// The set of parameters that can be used when creating an order.
+///
+/// For more details see https://stripe.com/docs/api#create_order.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderParams<'a> {
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<OrderItemParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ShippingDetails>,
+}
+
+/// The tracking details of a fulfilled order.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OrderShippingParams<'a> {
+    pub carrier: &'a str,
+    pub tracking_number: &'a str,
+}
+
+/// The set of parameters that can be used when updating an order.
+///
+/// For more details see https://stripe.com/docs/api#update_order.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OrderUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_shipping_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<OrderShippingParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+}
+
+/// The set of parameters that can be used when paying for an order.
+///
+/// Either a `customer` or a `source` must be provided.
+///
+/// For more details see https://stripe.com/docs/api#pay_order.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderPayParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<PaymentSourceParams<'a>>,
+}
+
+/// The set of parameters that can be used when returning an order.
+///
+/// For more details see https://stripe.com/docs/api#return_order.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct OrderReturnParams<'a> {
+    /// The items to return, which defaults to all of the order's items.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<OrderItemParams<'a>>,
+}
+
+/// The set of parameters that can be used when listing orders.
+///
+/// For more details see https://stripe.com/docs/api#list_orders.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OrderListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ids: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+}
+
+impl Order {
+    /// Creates a new order.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_order.
+    pub fn create(client: &Client, params: OrderParams) -> Result<Order, Error> {
+        client.post("/orders", params)
+    }
+
+    /// Retrieves the details of an order.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_order.
+    pub fn retrieve(client: &Client, order_id: &str) -> Result<Order, Error> {
+        client.get(&format!("/orders/{}", order_id))
+    }
+
+    /// Updates an order's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_order.
+    pub fn update(
+        client: &Client,
+        order_id: &str,
+        params: OrderUpdateParams,
+    ) -> Result<Order, Error> {
+        client.post(&format!("/orders/{}", order_id), params)
+    }
+
+    /// Lists all orders.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_orders.
+    pub fn list(client: &Client, params: OrderListParams) -> Result<List<Order>, Error> {
+        client.get(&format!("/orders?{}", qs::to_string(&params)?))
+    }
+
+    /// Pays for an order.
+    ///
+    /// For more details see https://stripe.com/docs/api#pay_order.
+    pub fn pay(client: &Client, order_id: &str, params: OrderPayParams) -> Result<Order, Error> {
+        client.post(&format!("/orders/{}/pay", order_id), params)
+    }
+
+    /// Returns all or part of a paid order, refunding the returned items.
+    ///
+    /// For more details see https://stripe.com/docs/api#return_order.
+    pub fn return_order(
+        client: &Client,
+        order_id: &str,
+        params: OrderReturnParams,
+    ) -> Result<OrderReturn, Error> {
+        client.post(&format!("/orders/{}/returns", order_id), params)
+    }
+}
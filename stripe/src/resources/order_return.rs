@@ -1,5 +1,8 @@
-use params::{Identifiable, List, Timestamp};
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, RangeQuery, Timestamp};
 use resources::{Currency, OrderItem};
+use serde_qs as qs;
 
 /// The resource representing a Stripe order return.
 ///
@@ -11,10 +14,10 @@ pub struct OrderReturn {
     pub amount: u64,
     pub created: Timestamp,
     pub currency: Currency,
-    pub items: List<OrderItem>,
+    pub items: Vec<OrderItem>,
     pub livemode: bool,
-    pub order: String,
-    pub refund: String,
+    pub order: Option<String>,
+    pub refund: Option<String>,
 }
 
 impl Identifiable for OrderReturn {
@@ -23,3 +26,35 @@ impl Identifiable for OrderReturn {
     }
 }
 
+/// The set of parameters that can be used when listing order returns.
+///
+/// For more details see https://stripe.com/docs/api#list_order_returns.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OrderReturnListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl OrderReturn {
+    /// Retrieves the details of an order return.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_order_return.
+    pub fn retrieve(client: &Client, return_id: &str) -> Result<OrderReturn, Error> {
+        client.get(&format!("/order_returns/{}", return_id))
+    }
+
+    /// Lists all order returns.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_order_returns.
+    pub fn list(client: &Client, params: OrderReturnListParams) -> Result<List<OrderReturn>, Error> {
+        client.get(&format!("/order_returns?{}", qs::to_string(&params)?))
+    }
+}
@@ -1,7 +1,9 @@
 use client::Client;
 use error::Error;
 use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
-use resources::{Charge, Currency, ShippingDetails};
+use resources::{
+    Charge, Currency, PaymentMethodType, Refund, RefundParams, RefundReason, ShippingDetails,
+};
 use serde_qs as qs;
 
 /// The resource representing a Stripe PaymentIntent object.
@@ -11,7 +13,8 @@ use serde_qs as qs;
 pub struct PaymentIntent {
     pub id: String,
     pub object: String,
-    pub allowed_source_types: Vec<String>,
+    #[serde(default)]
+    pub allowed_source_types: Vec<String>, // (replaced by `payment_method_types`)
     pub amount: u64,
     pub amount_capturable: u64,
     pub amount_received: u64,
@@ -30,12 +33,17 @@ pub struct PaymentIntent {
     pub last_payment_error: Option<PaymentError>,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub next_source_action: Option<NextSourceAction>,
+    pub next_action: Option<NextAction>,
+    pub next_source_action: Option<NextSourceAction>, // (replaced by `next_action`)
     pub on_behalf_of: Option<String>,
+    pub payment_method: Option<String>,
+    #[serde(default)]
+    pub payment_method_types: Vec<PaymentMethodType>,
     pub receipt_email: Option<String>,
     pub review: Option<String>,
+    pub setup_future_usage: Option<SetupFutureUsage>,
     pub shipping: Option<ShippingDetails>,
-    pub source: String,
+    pub source: Option<String>,
     pub statement_descriptor: Option<String>,
     pub status: PaymentIntentStatus,
     pub transfer_data: Option<TransferData>,
@@ -93,9 +101,11 @@ pub enum PaymentErrorType {
 #[derive(Deserialize, Serialize, PartialEq, Debug, Clone, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum PaymentIntentStatus {
-    RequiresSource,
+    RequiresPaymentMethod,
+    RequiresSource, // (replaced by `requires_payment_method`)
     RequiresConfirmation,
-    RequiresSourceAction,
+    RequiresAction,
+    RequiresSourceAction, // (replaced by `requires_action`)
     Processing,
     RequiresCapture,
     Canceled,
@@ -173,6 +183,54 @@ pub struct AuthorizeWithUrl {
     pub url: Option<String>,
 }
 
+/// An enum representing the possible values of a `NextAction`'s `type` field.
+///
+/// For more details see [https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action-type](https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action-type).
+#[derive(Deserialize, Serialize, PartialEq, Debug, Clone, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NextActionType {
+    RedirectToUrl,
+    UseStripeSdk,
+    #[serde(other)]
+    Other,
+}
+
+/// The resource representing the action a customer must take to authenticate a payment.
+///
+/// For more details see [https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action](https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NextAction {
+    #[serde(rename = "type")]
+    pub action_type: NextActionType,
+    pub redirect_to_url: Option<RedirectToUrl>,
+    /// Only intended to be used by Stripe.js, which depends on its contents to invoke authentication flows.
+    #[serde(default)]
+    pub use_stripe_sdk: Option<serde_json::Value>,
+}
+
+/// The resource representing a Stripe RedirectToUrl object.
+///
+/// For more details see [https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action-redirect_to_url](https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action-redirect_to_url).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RedirectToUrl {
+    /// If the customer does not exit their browser while authenticating, they will be redirected to this specified URL after completion.
+    pub return_url: Option<String>,
+    /// The URL you must redirect your customer to in order to authenticate the payment.
+    pub url: Option<String>,
+}
+
+/// An enum representing the possible values of a PaymentIntent's `setup_future_usage` field.
+///
+/// For more details see [https://stripe.com/docs/api/payment_intents/object#payment_intent_object-setup_future_usage](https://stripe.com/docs/api/payment_intents/object#payment_intent_object-setup_future_usage).
+#[derive(Copy, Deserialize, Serialize, PartialEq, Debug, Clone, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupFutureUsage {
+    OnSession,
+    OffSession,
+    #[serde(other)]
+    Other,
+}
+
 /// The resource representing a Stripe TransferData object.
 ///
 /// For more details see [https://stripe.com/docs/api/payment_intents/object#payment_intent_object-transfer_data](https://stripe.com/docs/api/payment_intents/object#payment_intent_object-transfer_data).
@@ -186,6 +244,7 @@ pub struct TransferData {
 /// For more details see [https://stripe.com/docs/api/payment_intents/create](https://stripe.com/docs/api/payment_intents/create)
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct PaymentIntentCreateParams {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub allowed_source_types: Vec<String>, //The list of source types (e.g. card) that this PaymentIntent is allowed to use.
     pub amount: u64,
     pub currency: Currency,
@@ -204,12 +263,18 @@ pub struct PaymentIntentCreateParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_behalf_of: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub payment_method_types: Vec<PaymentMethodType>, // (defaults to `card`)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub return_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub save_source_to_customer: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<SetupFutureUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping: Option<ShippingDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
@@ -239,10 +304,16 @@ pub struct PaymentIntentUpdateParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub payment_method_types: Vec<PaymentMethodType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub save_source_to_customer: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<SetupFutureUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping: Option<ShippingDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
@@ -255,6 +326,10 @@ pub struct PaymentIntentUpdateParams {
 /// For more details see [https://stripe.com/docs/api/payment_intents/confirm](https://stripe.com/docs/api/payment_intents/confirm)
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct PaymentIntentConfirmParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub payment_method_types: Vec<PaymentMethodType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -262,6 +337,8 @@ pub struct PaymentIntentConfirmParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub save_source_to_customer: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<SetupFutureUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping: Option<ShippingDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
@@ -373,6 +450,28 @@ impl PaymentIntent {
         )
     }
 
+    /// Refunds all of the captured funds of a PaymentIntent, or `amount` of them if given.
+    ///
+    /// The returned refund's `charge` is expanded, and that charge's `amount_refunded`
+    /// and `refunds` already include the new refund.
+    ///
+    /// For more details see [https://stripe.com/docs/api/refunds/create](https://stripe.com/docs/api/refunds/create).
+    pub fn refund(
+        client: &Client,
+        payment_intent_id: &str,
+        amount: Option<u64>,
+        reason: Option<RefundReason>,
+    ) -> Result<Refund, Error> {
+        let params = RefundParams {
+            payment_intent: Some(payment_intent_id.to_string()),
+            amount,
+            expand: vec!["charge".to_string()],
+            reason,
+            ..Default::default()
+        };
+        Refund::create(client, params)
+    }
+
     /// List all payment_intents.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/list](https://stripe.com/docs/api/payment_intents/list).
@@ -0,0 +1,242 @@
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, Metadata, Timestamp};
+use resources::{Address, CardType, CheckResult};
+use serde_qs as qs;
+
+/// An enum representing the possible values of a `PaymentMethod`'s `type` field.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/object#payment_method_object-type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodType {
+    Card,
+    CardPresent,
+    #[serde(other)]
+    Other,
+}
+
+/// The billing information associated with a payment method.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/object#payment_method_object-billing_details.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BillingDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}
+
+/// An enum representing the possible values of a `PaymentMethodCard`'s `brand` field.
+///
+/// Unlike `CardBrand`, payment methods identify the brand with a lowercase code.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodCardBrand {
+    Amex,
+    Diners,
+    Discover,
+    Jcb,
+    Mastercard,
+    Unionpay,
+    Visa,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The results of the checks made when the card was added.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodCardChecks {
+    pub address_line1_check: Option<CheckResult>,
+    pub address_postal_code_check: Option<CheckResult>,
+    pub cvc_check: Option<CheckResult>,
+}
+
+/// The details of a payment method with the `card` type.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/object#payment_method_object-card.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodCard {
+    pub brand: PaymentMethodCardBrand,
+    pub checks: Option<PaymentMethodCardChecks>,
+    pub country: Option<String>, // eg. "US"
+    pub exp_month: u32,
+    pub exp_year: u32,
+    pub fingerprint: Option<String>,
+    pub funding: CardType,
+    pub last4: String,
+}
+
+/// The resource representing a Stripe payment method.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethod {
+    pub id: String,
+    pub billing_details: BillingDetails,
+    pub card: Option<PaymentMethodCard>,
+    pub created: Timestamp,
+    pub customer: Option<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    #[serde(rename = "type")]
+    pub payment_method_type: PaymentMethodType,
+}
+
+impl Identifiable for PaymentMethod {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The card of a new payment method, either as a token or as raw card details.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/create#create_payment_method-card.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum PaymentMethodCardParams<'a> {
+    Token {
+        token: &'a str,
+    },
+    Details {
+        number: &'a str,
+        exp_month: u32,
+        exp_year: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cvc: Option<&'a str>,
+    },
+}
+
+/// The set of parameters that can be used when creating a payment method.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/create.
+#[derive(Clone, Debug, Serialize)]
+pub struct PaymentMethodParams<'a> {
+    #[serde(rename = "type")]
+    pub payment_method_type: PaymentMethodType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_details: Option<BillingDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<PaymentMethodCardParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The card details which can be changed on an existing payment method.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentMethodCardUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<u32>,
+}
+
+/// The set of parameters that can be used when updating a payment method.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/update.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentMethodUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_details: Option<BillingDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<PaymentMethodCardUpdateParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing a customer's payment methods.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/list.
+#[derive(Clone, Debug, Serialize)]
+pub struct PaymentMethodListParams<'a> {
+    pub customer: &'a str,
+    #[serde(rename = "type")]
+    pub payment_method_type: PaymentMethodType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> PaymentMethodListParams<'a> {
+    /// Creates the parameters for listing the payment methods of `customer` with the given type.
+    pub fn new(
+        customer: &'a str,
+        payment_method_type: PaymentMethodType,
+    ) -> PaymentMethodListParams<'a> {
+        PaymentMethodListParams {
+            customer,
+            payment_method_type,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct PaymentMethodAttachParams<'a> {
+    customer: &'a str,
+}
+
+impl PaymentMethod {
+    /// Creates a new payment method.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/create.
+    pub fn create(client: &Client, params: PaymentMethodParams) -> Result<PaymentMethod, Error> {
+        client.post("/payment_methods", params)
+    }
+
+    /// Retrieves the details of a payment method.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/retrieve.
+    pub fn retrieve(client: &Client, payment_method_id: &str) -> Result<PaymentMethod, Error> {
+        client.get(&format!("/payment_methods/{}", payment_method_id))
+    }
+
+    /// Updates a payment method's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/update.
+    pub fn update(
+        client: &Client,
+        payment_method_id: &str,
+        params: PaymentMethodUpdateParams,
+    ) -> Result<PaymentMethod, Error> {
+        client.post(&format!("/payment_methods/{}", payment_method_id), params)
+    }
+
+    /// Lists the payment methods of a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/list.
+    pub fn list(
+        client: &Client,
+        params: PaymentMethodListParams,
+    ) -> Result<List<PaymentMethod>, Error> {
+        client.get(&format!("/payment_methods?{}", qs::to_string(&params)?))
+    }
+
+    /// Attaches a payment method to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/attach.
+    pub fn attach(
+        client: &Client,
+        payment_method_id: &str,
+        customer_id: &str,
+    ) -> Result<PaymentMethod, Error> {
+        let params = PaymentMethodAttachParams { customer: customer_id };
+        client.post(&format!("/payment_methods/{}/attach", payment_method_id), params)
+    }
+
+    /// Detaches a payment method from its customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/detach.
+    pub fn detach(client: &Client, payment_method_id: &str) -> Result<PaymentMethod, Error> {
+        client.post_empty(&format!("/payment_methods/{}/detach", payment_method_id))
+    }
+}
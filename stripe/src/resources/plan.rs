@@ -1,52 +1,272 @@
 use client::Client;
 use error::Error;
-use params::{Identifiable, Metadata, Timestamp};
-use resources::{Currency, Deleted};
+use params::{Expandable, Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::{Currency, Deleted, Product};
+use serde;
+use serde_qs as qs;
 
-/// The set of parameters that can be used when creating or updating a plan.
+/// An enum representing the possible values of a `Plan`'s `billing_scheme` field.
 ///
-/// For more details see https://stripe.com/docs/api#create_plan and https://stripe.com/docs/api#update_plan.
+/// For more details see https://stripe.com/docs/api#plan_object-billing_scheme.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingScheme {
+    PerUnit,
+    Tiered,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of a `Plan`'s `tiers_mode` field.
+///
+/// For more details see https://stripe.com/docs/api#plan_object-tiers_mode.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TiersMode {
+    Graduated,
+    Volume,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of a `Plan`'s `usage_type` field.
+///
+/// For more details see https://stripe.com/docs/api#plan_object-usage_type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageType {
+    Licensed,
+    Metered,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of a `Plan`'s `aggregate_usage` field.
+///
+/// For more details see https://stripe.com/docs/api#plan_object-aggregate_usage.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregateUsage {
+    Sum,
+    LastDuringPeriod,
+    LastEver,
+    Max,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of a `TransformUsage`'s `round` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransformUsageRound {
+    Up,
+    Down,
+    #[serde(other)]
+    Other,
+}
+
+/// Divides the reported usage of a metered plan before it is billed.
+///
+/// For more details see https://stripe.com/docs/api#plan_object-transform_usage.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TransformUsage {
+    pub divide_by: u64,
+    pub round: TransformUsageRound,
+}
+
+/// The upper bound of a pricing tier.
+///
+/// Stripe encodes the last, unbounded tier as `inf` in requests and `null` in responses.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum UpTo {
+    Inf,
+    Max(u64),
+}
+
+impl serde::Serialize for UpTo {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            UpTo::Inf => serializer.serialize_str("inf"),
+            UpTo::Max(n) => serializer.serialize_u64(n),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for UpTo {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let up_to: Option<u64> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(up_to.map(UpTo::Max).unwrap_or(UpTo::Inf))
+    }
+}
+
+/// A pricing tier of a plan with a `tiered` billing scheme.
+///
+/// For more details see https://stripe.com/docs/api#plan_object-tiers.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PlanTier {
+    pub amount: Option<u64>,
+    pub flat_amount: Option<u64>,
+    pub up_to: UpTo,
+}
+
+/// The resource representing a Stripe plan.
+///
+/// For more details see https://stripe.com/docs/api#plans.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Plan {
+    pub id: String,
+    pub active: bool,
+    pub aggregate_usage: Option<AggregateUsage>,
+    pub amount: Option<u64>,
+    pub billing_scheme: BillingScheme,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub interval: String, // (day, week, month, year)
+    pub interval_count: u64,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub nickname: Option<String>,
+    pub product: Expandable<Product>,
+    #[serde(default)]
+    pub tiers: Option<Vec<PlanTier>>,
+    pub tiers_mode: Option<TiersMode>,
+    pub transform_usage: Option<TransformUsage>,
+    pub trial_period_days: Option<u64>,
+    pub usage_type: UsageType,
+}
+
+/// The parameters of a product created inline alongside a plan.
+///
+/// For more details see https://stripe.com/docs/api#create_plan-product.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct PlanParams<'a> {
+pub struct PlanProductParams<'a> {
+    pub name: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<u64>,
+    pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<Currency>,
+    pub statement_descriptor: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interval: Option<&'a str>, // (day, week, month, year)
+    pub unit_label: Option<&'a str>,
+}
+
+/// The product that a new plan prices, either an existing product or one to create.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum PlanProduct<'a> {
+    Id(&'a str),
+    Inline(PlanProductParams<'a>),
+}
+
+/// The parameters of a pricing tier used when creating a plan.
+///
+/// Each tier must set at least one of `amount` or `flat_amount`.
+#[derive(Clone, Debug, Serialize)]
+pub struct PlanTierParams {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<&'a str>,
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_amount: Option<u64>,
+    pub up_to: UpTo,
+}
 
+/// The set of parameters that can be used when creating a plan.
+///
+/// For more details see https://stripe.com/docs/api#create_plan.
+#[derive(Clone, Debug, Serialize)]
+pub struct PlanParams<'a> {
+    pub currency: Currency,
+    pub interval: &'a str, // (day, week, month, year)
+    pub product: PlanProduct<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_usage: Option<AggregateUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>, // (required unless `billing_scheme` is `tiered`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_scheme: Option<BillingScheme>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_count: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statement_descriptor: Option<&'a str>,
+    pub nickname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tiers: Vec<PlanTierParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers_mode: Option<TiersMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform_usage: Option<TransformUsage>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_type: Option<UsageType>,
 }
 
-/// The resource representing a Stripe plan.
+impl<'a> PlanParams<'a> {
+    /// Creates the parameters for a plan billed every `interval` for `product`.
+    pub fn new(currency: Currency, interval: &'a str, product: PlanProduct<'a>) -> PlanParams<'a> {
+        PlanParams {
+            currency,
+            interval,
+            product,
+            id: None,
+            active: None,
+            aggregate_usage: None,
+            amount: None,
+            billing_scheme: None,
+            interval_count: None,
+            metadata: None,
+            nickname: None,
+            tiers: Vec::new(),
+            tiers_mode: None,
+            transform_usage: None,
+            trial_period_days: None,
+            usage_type: None,
+        }
+    }
+}
+
+/// The set of parameters that can be used when updating a plan.
 ///
-/// For more details see https://stripe.com/docs/api#plans.
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct Plan {
-    pub id: String,
-    pub amount: u64,
-    pub created: Timestamp,
-    pub currency: Currency,
-    pub interval: String, // (day, week, month, year)
-    pub interval_count: u64,
-    pub livemode: bool,
-    pub metadata: Metadata,
-    pub nickname: Option<String>,
-    pub statement_descriptor: Option<String>,
+/// For more details see https://stripe.com/docs/api#update_plan.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PlanUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u64>,
 }
 
+/// The set of parameters that can be used when listing plans.
+///
+/// For more details see https://stripe.com/docs/api#list_plans.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PlanListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
 impl Plan {
     /// Creates a new plan.
     ///
@@ -65,7 +285,7 @@ impl Plan {
     /// Updates a plan's properties.
     ///
     /// For more details see https://stripe.com/docs/api#update_plan.
-    pub fn update(client: &Client, plan_id: &str, params: PlanParams) -> Result<Plan, Error> {
+    pub fn update(client: &Client, plan_id: &str, params: PlanUpdateParams) -> Result<Plan, Error> {
         client.post(&format!("/plans/{}", plan_id), params)
     }
 
@@ -75,6 +295,13 @@ impl Plan {
     pub fn delete(client: &Client, plan_id: &str) -> Result<Deleted, Error> {
         client.delete(&format!("/plans/{}", plan_id))
     }
+
+    /// Lists all plans.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_plans.
+    pub fn list(client: &Client, params: PlanListParams) -> Result<List<Plan>, Error> {
+        client.get(&format!("/plans?{}", qs::to_string(&params)?))
+    }
 }
 
 impl Identifiable for Plan {
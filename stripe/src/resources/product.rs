@@ -1,5 +1,8 @@
-use params::{Identifiable, List, Metadata, Timestamp};
-use resources::Sku;
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::{Deleted, Sku};
+use serde_qs as qs;
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PackageDimensions {
@@ -9,6 +12,18 @@ pub struct PackageDimensions {
     pub width: f64,
 }
 
+/// An enum representing the possible values of a `Product`'s `type` field.
+///
+/// For more details see https://stripe.com/docs/api#product_object-type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProductType {
+    Good,
+    Service,
+    #[serde(other)]
+    Other,
+}
+
 /// The resource representing a Stripe product.
 ///
 /// For more details see https://stripe.com/docs/api#product_object.
@@ -17,18 +32,25 @@ pub struct Product {
     pub id: String,
     pub object: String,
     pub active: Option<bool>,
+    #[serde(default)]
     pub attributes: Vec<String>,
     pub caption: Option<String>,
     pub created: Timestamp,
+    #[serde(default)]
     pub deactivate_on: Vec<String>,
     pub description: Option<String>,
+    #[serde(default)]
     pub images: Vec<String>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub name: String,
     pub package_dimensions: Option<PackageDimensions>,
     pub shippable: Option<bool>,
-    pub skus: List<Sku>,
+    pub skus: Option<List<Sku>>,
+    pub statement_descriptor: Option<String>,
+    #[serde(rename = "type")]
+    pub product_type: ProductType,
+    pub unit_label: Option<String>,
     pub updated: Timestamp,
     pub url: Option<String>,
 }
@@ -37,4 +59,109 @@ impl Identifiable for Product {
     fn id(&self) -> &str {
         &self.id
     }
-}
\ No newline at end of file
+}
+
+/// The set of parameters that can be used when creating or updating a product.
+///
+/// For more details see https://stripe.com/docs/api#create_product and https://stripe.com/docs/api#update_product.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProductParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>, // (only used when creating a product)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivate_on: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_dimensions: Option<PackageDimensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shippable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<ProductType>, // (only used when creating a product)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_label: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when listing products.
+///
+/// For more details see https://stripe.com/docs/api#list_products.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProductListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ids: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shippable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<ProductType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
+impl Product {
+    /// Creates a new product.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_product.
+    pub fn create(client: &Client, params: ProductParams) -> Result<Product, Error> {
+        client.post("/products", params)
+    }
+
+    /// Retrieves the details of a product.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_product.
+    pub fn retrieve(client: &Client, product_id: &str) -> Result<Product, Error> {
+        client.get(&format!("/products/{}", product_id))
+    }
+
+    /// Updates a product's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_product.
+    pub fn update(
+        client: &Client,
+        product_id: &str,
+        params: ProductParams,
+    ) -> Result<Product, Error> {
+        client.post(&format!("/products/{}", product_id), params)
+    }
+
+    /// Lists all products.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_products.
+    pub fn list(client: &Client, params: ProductListParams) -> Result<List<Product>, Error> {
+        client.get(&format!("/products?{}", qs::to_string(&params)?))
+    }
+
+    /// Deletes a product which has no SKUs or prices.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_product.
+    pub fn delete(client: &Client, product_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/products/{}", product_id))
+    }
+}
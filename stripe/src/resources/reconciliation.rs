@@ -0,0 +1,177 @@
+use client::Client;
+use error::Error;
+use params::List;
+use resources::{BalanceTransaction, BalanceTransactionListParams, BalanceTransactionType, Payout};
+use std::collections::HashMap;
+
+/// The gross amount, fees and net amount of a group of balance transactions.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ReconciliationTotals {
+    pub count: u64,
+    pub gross: i64,
+    pub fee: i64,
+    pub net: i64,
+}
+
+impl ReconciliationTotals {
+    fn add(&mut self, transaction: &BalanceTransaction) {
+        self.count += 1;
+        self.gross += transaction.amount;
+        self.fee += transaction.fee;
+        self.net += transaction.net;
+    }
+}
+
+/// A report of the charges, refunds, fees, etc. which were settled by a payout.
+///
+/// For more details see https://stripe.com/docs/reporting/payout-reconciliation.
+#[derive(Clone, Debug)]
+pub struct PayoutReconciliation {
+    pub payout: Payout,
+    /// The balance transactions settled by the payout, with their `source` expanded.
+    ///
+    /// This does not include the balance transaction of the payout itself.
+    pub transactions: Vec<BalanceTransaction>,
+    pub by_type: HashMap<BalanceTransactionType, ReconciliationTotals>,
+    pub total: ReconciliationTotals,
+}
+
+impl PayoutReconciliation {
+    /// Builds a report from a payout and the balance transactions listed for it.
+    pub fn new(payout: Payout, transactions: Vec<BalanceTransaction>) -> PayoutReconciliation {
+        let transactions: Vec<_> = transactions
+            .into_iter()
+            .filter(|txn| {
+                txn.source.as_ref().and_then(|source| source.id()) != Some(payout.id.as_str())
+            })
+            .collect();
+
+        let mut by_type = HashMap::new();
+        let mut total = ReconciliationTotals::default();
+        for txn in &transactions {
+            by_type
+                .entry(txn.transaction_type)
+                .or_insert_with(ReconciliationTotals::default)
+                .add(txn);
+            total.add(txn);
+        }
+
+        PayoutReconciliation {
+            payout,
+            transactions,
+            by_type,
+            total,
+        }
+    }
+
+    /// Whether the net amount of the settled transactions matches the amount paid out.
+    pub fn is_balanced(&self) -> bool {
+        self.discrepancy() == 0
+    }
+
+    /// The amount paid out minus the net amount of the settled transactions.
+    pub fn discrepancy(&self) -> i64 {
+        self.payout.amount as i64 - self.total.net
+    }
+}
+
+impl Payout {
+    /// Fetches a payout and all of the balance transactions it settled.
+    ///
+    /// Use `PayoutReconciliation::is_balanced` to check the report against the payout's amount.
+    ///
+    /// For more details see https://stripe.com/docs/reporting/payout-reconciliation.
+    pub fn reconcile(client: &Client, payout_id: &str) -> Result<PayoutReconciliation, Error> {
+        let payout = Payout::retrieve(client, payout_id)?;
+
+        // N.B. paginate with `starting_after` so the `payout` filter and `expand`
+        //      are kept for every page (unlike `List::next`)
+        let mut transactions = Vec::new();
+        loop {
+            let params = BalanceTransactionListParams {
+                expand: vec!["data.source"],
+                limit: Some(100),
+                payout: Some(payout_id),
+                starting_after: transactions
+                    .last()
+                    .map(|txn: &BalanceTransaction| txn.id.as_str()),
+                ..Default::default()
+            };
+            let page: List<BalanceTransaction> = BalanceTransaction::list(client, params)?;
+            let has_more = page.has_more && !page.data.is_empty();
+            transactions.extend(page.data);
+            if !has_more {
+                break;
+            }
+        }
+
+        Ok(PayoutReconciliation::new(payout, transactions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PayoutReconciliation;
+    use resources::{BalanceTransaction, BalanceTransactionType, Payout};
+    use serde_json as json;
+
+    fn transaction(
+        id: &str,
+        kind: &str,
+        source: &str,
+        amount: i64,
+        fee: i64,
+    ) -> BalanceTransaction {
+        json::from_str(&format!(
+            r#"{{
+                "id": "{}", "object": "balance_transaction", "amount": {}, "available_on": 1542931200,
+                "created": 1542400000, "currency": "usd", "description": null, "fee": {},
+                "fee_details": [], "net": {}, "source": "{}", "status": "available", "type": "{}"
+            }}"#,
+            id, amount, fee, amount - fee, source, kind
+        )).unwrap()
+    }
+
+    fn payout(amount: u64) -> Payout {
+        json::from_str(&format!(
+            r#"{{
+                "id": "po_1", "object": "payout", "amount": {}, "arrival_date": 1543017600,
+                "balance_transaction": "txn_po", "created": 1542931200, "currency": "usd",
+                "description": "STRIPE PAYOUT", "destination": "ba_1", "failure_balance_transaction": null,
+                "failure_code": null, "failure_message": null, "livemode": false, "metadata": {{}},
+                "method": "standard", "source_type": "card", "statement_descriptor": null,
+                "status": "paid", "type": "bank_account"
+            }}"#,
+            amount
+        )).unwrap()
+    }
+
+    #[test]
+    fn reconcile_payout() {
+        let transactions = vec![
+            transaction("txn_1", "charge", "ch_1", 10000, 320),
+            transaction("txn_2", "charge", "ch_2", 5000, 175),
+            transaction("txn_3", "refund", "re_1", -2500, 0),
+            transaction("txn_po", "payout", "po_1", -12005, 0),
+        ];
+        let report = PayoutReconciliation::new(payout(12005), transactions);
+
+        assert_eq!(report.transactions.len(), 3);
+        let charges = report.by_type[&BalanceTransactionType::Charge];
+        assert_eq!(
+            (charges.count, charges.gross, charges.fee, charges.net),
+            (2, 15000, 495, 14505)
+        );
+        let refunds = report.by_type[&BalanceTransactionType::Refund];
+        assert_eq!(
+            (refunds.count, refunds.gross, refunds.fee, refunds.net),
+            (1, -2500, 0, -2500)
+        );
+        assert_eq!(report.total.net, 12005);
+        assert!(report.is_balanced());
+
+        let report = PayoutReconciliation::new(payout(12000), report.transactions);
+        assert!(!report.is_balanced());
+        assert_eq!(report.discrepancy(), -5);
+    }
+}
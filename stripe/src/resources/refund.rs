@@ -1,7 +1,7 @@
 use client::Client;
 use error::Error;
-use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
-use resources::Currency;
+use params::{Expandable, Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::{Charge, Currency};
 use serde_qs as qs;
 
 /// The resource representing a Stripe refund.
@@ -13,7 +13,7 @@ pub struct Refund {
     pub object: String,
     pub amount: u64,
     pub balance_transaction: String,
-    pub charge: String,
+    pub charge: Expandable<Charge>,
     pub created: Timestamp,
     pub currency: Currency,
     pub failure_balance_transaction: Option<String>,
@@ -72,14 +72,26 @@ pub enum RefundFailureReason {
 
 /// The set of parameters that can be used when creating refund object.
 ///
+/// Exactly one of `charge` or `payment_intent` must be set.
+///
 /// For more details see [https://stripe.com/docs/api/refunds/create](https://stripe.com/docs/api/refunds/create).
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RefundParams {
-    pub charge: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub amount: Option<u64>,
-    pub metadata: Metadata,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub expand: Vec<String>, // e.g. "charge"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<RefundReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub refund_application_fee: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reverse_transfer: Option<bool>,
 }
 
@@ -1,4 +1,23 @@
-use params::{Identifiable, Timestamp};
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, RangeQuery, Timestamp};
+use serde_qs as qs;
+
+/// An enum representing the possible values of a `Review`'s `reason` field.
+///
+/// For more details see https://stripe.com/docs/api#review_object-reason.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewReason {
+    Rule,
+    Manual,
+    Approved,
+    Refunded,
+    RefundedAsFraud,
+    Disputed,
+    #[serde(other)]
+    Other,
+}
 
 /// The resource representing a Stripe review of a payment.
 ///
@@ -7,11 +26,12 @@ use params::{Identifiable, Timestamp};
 pub struct Review {
     pub id: String,
     pub object: String,
-    pub charge: String,
+    pub charge: Option<String>,
     pub created: Timestamp,
     pub livemode: bool,
     pub open: bool,
-    pub reason: String,
+    pub payment_intent: Option<String>,
+    pub reason: ReviewReason,
 }
 
 impl Identifiable for Review {
@@ -19,3 +39,43 @@ impl Identifiable for Review {
         &self.id
     }
 }
+
+/// The set of parameters that can be used when listing reviews.
+///
+/// Only open reviews are returned.
+///
+/// For more details see https://stripe.com/docs/api#list_reviews.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReviewListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl Review {
+    /// Retrieves the details of a review.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_review.
+    pub fn retrieve(client: &Client, review_id: &str) -> Result<Review, Error> {
+        client.get(&format!("/reviews/{}", review_id))
+    }
+
+    /// Lists the reviews which are open and waiting to be approved.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_reviews.
+    pub fn list(client: &Client, params: ReviewListParams) -> Result<List<Review>, Error> {
+        client.get(&format!("/reviews?{}", qs::to_string(&params)?))
+    }
+
+    /// Approves an open review, closing it and removing it from the list of reviews.
+    ///
+    /// For more details see https://stripe.com/docs/api#approve_review.
+    pub fn approve(client: &Client, review_id: &str) -> Result<Review, Error> {
+        client.post_empty(&format!("/reviews/{}/approve", review_id))
+    }
+}
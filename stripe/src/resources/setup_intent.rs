@@ -0,0 +1,215 @@
+use client::Client;
+use error::Error;
+use ids::CustomerId;
+use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::{NextAction, PaymentError, PaymentMethodType};
+use serde_qs as qs;
+
+/// The resource representing a Stripe SetupIntent object.
+///
+/// For more details see [https://stripe.com/docs/api/setup_intents](https://stripe.com/docs/api/setup_intents).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SetupIntent {
+    pub id: String,
+    pub object: String,
+    pub application: Option<String>,
+    pub cancellation_reason: Option<SetupIntentCancellationReason>,
+    pub client_secret: Option<String>,
+    pub created: Timestamp,
+    pub customer: Option<CustomerId>,
+    pub description: Option<String>,
+    pub last_setup_error: Option<PaymentError>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub next_action: Option<NextAction>,
+    pub on_behalf_of: Option<String>,
+    pub payment_method: Option<String>,
+    #[serde(default)]
+    pub payment_method_types: Vec<PaymentMethodType>,
+    pub status: SetupIntentStatus,
+    pub usage: SetupIntentUsage,
+}
+
+impl Identifiable for SetupIntent {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The resource representing a Stripe SetupIntentStatus object.
+///
+/// For more details see [https://stripe.com/docs/api/setup_intents/object#setup_intent_object-status](https://stripe.com/docs/api/setup_intents/object#setup_intent_object-status).
+#[derive(Deserialize, Serialize, PartialEq, Debug, Clone, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentStatus {
+    RequiresPaymentMethod,
+    RequiresConfirmation,
+    RequiresAction,
+    Processing,
+    Canceled,
+    Succeeded,
+    #[serde(other)]
+    Other,
+}
+
+/// The resource representing a Stripe SetupIntentUsage object.
+///
+/// For more details see [https://stripe.com/docs/api/setup_intents/object#setup_intent_object-usage](https://stripe.com/docs/api/setup_intents/object#setup_intent_object-usage).
+#[derive(Copy, Deserialize, Serialize, PartialEq, Debug, Clone, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentUsage {
+    OnSession,
+    OffSession,
+    #[serde(other)]
+    Other,
+}
+
+/// The resource representing a Stripe SetupIntentCancellationReason object.
+///
+/// For more details see [https://stripe.com/docs/api/setup_intents/object#setup_intent_object-cancellation_reason](https://stripe.com/docs/api/setup_intents/object#setup_intent_object-cancellation_reason).
+#[derive(Deserialize, Serialize, PartialEq, Debug, Clone, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentCancellationReason {
+    Abandoned,
+    Duplicate,
+    RequestedByCustomer,
+    #[serde(other)]
+    Other,
+}
+
+/// The set of parameters that can be used when creating a setup_intent object.
+///
+/// For more details see [https://stripe.com/docs/api/setup_intents/create](https://stripe.com/docs/api/setup_intents/create)
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SetupIntentCreateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub payment_method_types: Vec<PaymentMethodType>, // (defaults to `card`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>, // (only used with `confirm`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<SetupIntentUsage>, // (defaults to `off_session`)
+}
+
+/// The set of parameters that can be used when updating a setup_intent object.
+///
+/// For more details see [https://stripe.com/docs/api/setup_intents/update](https://stripe.com/docs/api/setup_intents/update)
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SetupIntentUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub payment_method_types: Vec<PaymentMethodType>,
+}
+
+/// The set of parameters that can be used when confirming a setup_intent object.
+///
+/// For more details see [https://stripe.com/docs/api/setup_intents/confirm](https://stripe.com/docs/api/setup_intents/confirm)
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SetupIntentConfirmParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+}
+
+/// The set of parameters that can be used when canceling a setup_intent object.
+///
+/// For more details see [https://stripe.com/docs/api/setup_intents/cancel](https://stripe.com/docs/api/setup_intents/cancel)
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SetupIntentCancelParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<SetupIntentCancellationReason>,
+}
+
+/// The set of parameters that can be used when listing setup_intents.
+///
+/// For more details see [https://stripe.com/docs/api/setup_intents/list](https://stripe.com/docs/api/setup_intents/list)
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupIntentListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<String>,
+}
+
+impl SetupIntent {
+    /// Creates a new setup_intent.
+    ///
+    /// For more details see [https://stripe.com/docs/api/setup_intents/create](https://stripe.com/docs/api/setup_intents/create).
+    pub fn create(client: &Client, params: SetupIntentCreateParams) -> Result<SetupIntent, Error> {
+        client.post("/setup_intents", params)
+    }
+
+    /// Retrieves the details of a setup_intent.
+    ///
+    /// For more details see [https://stripe.com/docs/api/setup_intents/retrieve](https://stripe.com/docs/api/setup_intents/retrieve).
+    pub fn retrieve(client: &Client, setup_intent_id: &str) -> Result<SetupIntent, Error> {
+        client.get(&format!("/setup_intents/{}", setup_intent_id))
+    }
+
+    /// Updates a setup_intent's properties.
+    ///
+    /// For more details see [https://stripe.com/docs/api/setup_intents/update](https://stripe.com/docs/api/setup_intents/update).
+    pub fn update(
+        client: &Client,
+        setup_intent_id: &str,
+        params: SetupIntentUpdateParams,
+    ) -> Result<SetupIntent, Error> {
+        client.post(&format!("/setup_intents/{}", setup_intent_id), params)
+    }
+
+    /// Confirm that the customer intends to set up the current or provided payment method for future payments.
+    ///
+    /// For more details see [https://stripe.com/docs/api/setup_intents/confirm](https://stripe.com/docs/api/setup_intents/confirm).
+    pub fn confirm(
+        client: &Client,
+        setup_intent_id: &str,
+        params: SetupIntentConfirmParams,
+    ) -> Result<SetupIntent, Error> {
+        client.post(&format!("/setup_intents/{}/confirm", setup_intent_id), params)
+    }
+
+    /// A SetupIntent object can be canceled when it is in one of these statuses: requires_payment_method, requires_confirmation, requires_action.
+    ///
+    /// For more details see [https://stripe.com/docs/api/setup_intents/cancel](https://stripe.com/docs/api/setup_intents/cancel).
+    pub fn cancel(
+        client: &Client,
+        setup_intent_id: &str,
+        params: SetupIntentCancelParams,
+    ) -> Result<SetupIntent, Error> {
+        client.post(&format!("/setup_intents/{}/cancel", setup_intent_id), params)
+    }
+
+    /// List all setup_intents.
+    ///
+    /// For more details see [https://stripe.com/docs/api/setup_intents/list](https://stripe.com/docs/api/setup_intents/list).
+    pub fn list(client: &Client, params: SetupIntentListParams) -> Result<List<SetupIntent>, Error> {
+        client.get(&format!("/setup_intents?{}", qs::to_string(&params)?))
+    }
+}
@@ -1,13 +1,49 @@
-use params::{Identifiable, Metadata, Timestamp};
-use resources::{Currency, PackageDimensions};
-use serde_json as json;
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, Metadata, Timestamp};
+use resources::{Currency, Deleted, PackageDimensions};
+use serde_qs as qs;
+use std::collections::HashMap;
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+/// An enum representing the possible values of an `Inventory`'s `type` field.
+///
+/// For more details see https://stripe.com/docs/api#sku_object-inventory-type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InventoryType {
+    Finite,
+    Bucket,
+    Infinite,
+    #[serde(other)]
+    Other,
+}
+
+/// An enum representing the possible values of an `Inventory`'s `value` field.
+///
+/// For more details see https://stripe.com/docs/api#sku_object-inventory-value.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InventoryValue {
+    InStock,
+    Limited,
+    OutOfStock,
+    #[serde(other)]
+    Other,
+}
+
+/// The stock of a SKU.
+///
+/// The `quantity` is only used by `Finite` inventories and the `value` by `Bucket` inventories.
+///
+/// For more details see https://stripe.com/docs/api#sku_object-inventory.
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Inventory {
-    pub quantity: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
     #[serde(rename = "type")]
-    pub inventory_type: String,
-    pub value: Option<String>,
+    pub inventory_type: InventoryType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<InventoryValue>,
 }
 
 /// The resource representing a Stripe Sku.
@@ -18,7 +54,8 @@ pub struct Sku {
     pub id: String,
     pub object: String,
     pub active: bool,
-    pub attributes: json::Value,
+    #[serde(default)]
+    pub attributes: HashMap<String, String>,
     pub created: Timestamp,
     pub currency: Currency,
     pub image: Option<String>,
@@ -37,3 +74,89 @@ impl Identifiable for Sku {
     }
 }
 
+/// The set of parameters that can be used when creating or updating a SKU.
+///
+/// For more details see https://stripe.com/docs/api#create_sku and https://stripe.com/docs/api#update_sku.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SkuParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>, // (only used when creating a sku)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory: Option<Inventory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_dimensions: Option<PackageDimensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when listing SKUs.
+///
+/// For more details see https://stripe.com/docs/api#list_skus.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SkuListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ids: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_stock: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl Sku {
+    /// Creates a new SKU for a product.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_sku.
+    pub fn create(client: &Client, params: SkuParams) -> Result<Sku, Error> {
+        client.post("/skus", params)
+    }
+
+    /// Retrieves the details of a SKU.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_sku.
+    pub fn retrieve(client: &Client, sku_id: &str) -> Result<Sku, Error> {
+        client.get(&format!("/skus/{}", sku_id))
+    }
+
+    /// Updates a SKU's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_sku.
+    pub fn update(client: &Client, sku_id: &str, params: SkuParams) -> Result<Sku, Error> {
+        client.post(&format!("/skus/{}", sku_id), params)
+    }
+
+    /// Lists all SKUs.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_skus.
+    pub fn list(client: &Client, params: SkuListParams) -> Result<List<Sku>, Error> {
+        client.get(&format!("/skus?{}", qs::to_string(&params)?))
+    }
+
+    /// Deletes a SKU which isn't used by any orders.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_sku.
+    pub fn delete(client: &Client, sku_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/skus/{}", sku_id))
+    }
+}
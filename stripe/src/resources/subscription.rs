@@ -1,7 +1,7 @@
 use client::Client;
 use error::Error;
 use params::{Identifiable, List, Metadata, Timestamp};
-use resources::{Discount, Plan};
+use resources::{DeletedDiscount, Discount, Plan};
 use serde_qs as qs;
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -57,6 +57,27 @@ pub enum TrialEnd<'a> {
     Special(&'a str),
 }
 
+/// The billing cycle anchor of a subscription, either reset to a timestamp or to `now`,
+/// or left `unchanged`.
+///
+/// For more details see https://stripe.com/docs/api#update_subscription-billing_cycle_anchor.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BillingCycleAnchor {
+    Now,
+    Unchanged,
+    Timestamp(Timestamp),
+}
+
+impl ::serde::Serialize for BillingCycleAnchor {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            BillingCycleAnchor::Now => serializer.serialize_str("now"),
+            BillingCycleAnchor::Unchanged => serializer.serialize_str("unchanged"),
+            BillingCycleAnchor::Timestamp(timestamp) => serializer.serialize_i64(timestamp),
+        }
+    }
+}
+
 /// The resource representing a Stripe subscription item.
 ///
 /// For more details see https://stripe.com/docs/api#subscription_items.
@@ -130,6 +151,16 @@ impl Subscription {
     ) -> Result<Subscription, Error> {
         client.delete(&format!("/subscriptions/{}?{}", subscription_id, qs::to_string(&params)?))
     }
+
+    /// Removes the discount currently applied to a subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_subscription_discount.
+    pub fn delete_discount(
+        client: &Client,
+        subscription_id: &str,
+    ) -> Result<DeletedDiscount, Error> {
+        client.delete(&format!("/subscriptions/{}/discount", subscription_id))
+    }
 }
 
 impl Identifiable for Subscription {
@@ -1,24 +1,28 @@
-use params::{Identifiable, List, Metadata, Timestamp};
+use client::Client;
+use error::Error;
+use ids::{AccountId, SourceTransactionId, TransferId, TransferReversalId};
+use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
 use resources::Currency;
+use serde_qs as qs;
 
 /// The resource representing a Stripe transfer reversal.
 ///
 /// For more details see https://stripe.com/docs/api#transfer_reversal_object.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TransferReversal {
-    pub id: String,
+    pub id: TransferReversalId,
     pub object: String,
     pub amount: u64,
-    pub balance_transaction: String,
+    pub balance_transaction: Option<String>,
     pub created: Timestamp,
     pub currency: Currency,
     pub metadata: Metadata,
-    pub transfer: String,
+    pub transfer: TransferId,
 }
 
 impl Identifiable for TransferReversal {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -27,27 +31,195 @@ impl Identifiable for TransferReversal {
 /// For more details see https://stripe.com/docs/api#transfer_object.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Transfer {
-    pub id: String,
+    pub id: TransferId,
     pub object: String,
     pub amount: u64,
     pub amount_reversed: u64,
-    pub balance_transaction: String,
+    pub balance_transaction: Option<String>,
     pub created: Timestamp,
     pub currency: Currency,
     pub description: Option<String>,
-    pub destination: String,
-    pub destination_payment: String,
+    pub destination: AccountId,
+    pub destination_payment: Option<String>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub reversals: List<TransferReversal>,
     pub reversed: bool,
-    pub source_transaction: String,
-    pub source_type: String,
-    pub transfer_group: String,
+    pub source_transaction: Option<SourceTransactionId>,
+    pub source_type: Option<String>,
+    pub transfer_group: Option<String>,
 }
 
 impl Identifiable for Transfer {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
+    }
+}
+
+/// The set of parameters that can be used when creating a transfer.
+///
+/// For more details see https://stripe.com/docs/api#create_transfer.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TransferParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+    pub destination: AccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_transaction: Option<SourceTransactionId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when updating a transfer.
+///
+/// For more details see https://stripe.com/docs/api#update_transfer.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TransferUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing transfers.
+///
+/// For more details see https://stripe.com/docs/api#list_transfers.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TransferListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<AccountId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+impl Transfer {
+    /// Creates a new transfer to a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_transfer.
+    pub fn create(client: &Client, params: TransferParams) -> Result<Transfer, Error> {
+        client.post("/transfers", params)
+    }
+
+    /// Retrieves the details of a transfer.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_transfer.
+    pub fn retrieve(client: &Client, transfer_id: &TransferId) -> Result<Transfer, Error> {
+        client.get(&format!("/transfers/{}", transfer_id))
+    }
+
+    /// Updates a transfer's description or metadata.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_transfer.
+    pub fn update(
+        client: &Client,
+        transfer_id: &TransferId,
+        params: TransferUpdateParams,
+    ) -> Result<Transfer, Error> {
+        client.post(&format!("/transfers/{}", transfer_id), params)
+    }
+
+    /// Lists all transfers.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_transfers.
+    pub fn list(client: &Client, params: TransferListParams) -> Result<List<Transfer>, Error> {
+        client.get(&format!("/transfers?{}", qs::to_string(&params)?))
+    }
+}
+
+/// The set of parameters that can be used when creating a transfer reversal.
+///
+/// For more details see https://stripe.com/docs/api#create_transfer_reversal.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TransferReversalParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+}
+
+/// The set of parameters that can be used when updating a transfer reversal.
+///
+/// For more details see https://stripe.com/docs/api#update_transfer_reversal.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TransferReversalUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing transfer reversals.
+///
+/// For more details see https://stripe.com/docs/api#list_transfer_reversals.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TransferReversalListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl TransferReversal {
+    /// Reverses all or part of a transfer.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_transfer_reversal.
+    pub fn create(
+        client: &Client,
+        transfer_id: &TransferId,
+        params: TransferReversalParams,
+    ) -> Result<TransferReversal, Error> {
+        client.post(&format!("/transfers/{}/reversals", transfer_id), params)
+    }
+
+    /// Retrieves the details of a transfer reversal.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_transfer_reversal.
+    pub fn retrieve(
+        client: &Client,
+        transfer_id: &TransferId,
+        reversal_id: &TransferReversalId,
+    ) -> Result<TransferReversal, Error> {
+        client.get(&format!("/transfers/{}/reversals/{}", transfer_id, reversal_id))
+    }
+
+    /// Updates a transfer reversal's metadata.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_transfer_reversal.
+    pub fn update(
+        client: &Client,
+        transfer_id: &TransferId,
+        reversal_id: &TransferReversalId,
+        params: TransferReversalUpdateParams,
+    ) -> Result<TransferReversal, Error> {
+        let path = format!("/transfers/{}/reversals/{}", transfer_id, reversal_id);
+        client.post(&path, params)
+    }
+
+    /// Lists the reversals of a transfer.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_transfer_reversals.
+    pub fn list(
+        client: &Client,
+        transfer_id: &TransferId,
+        params: TransferReversalListParams,
+    ) -> Result<List<TransferReversal>, Error> {
+        let query = qs::to_string(&params)?;
+        client.get(&format!("/transfers/{}/reversals?{}", transfer_id, query))
     }
 }
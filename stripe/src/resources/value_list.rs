@@ -0,0 +1,209 @@
+use client::Client;
+use error::Error;
+use params::{Identifiable, List, Metadata, RangeQuery, Timestamp};
+use resources::Deleted;
+use serde_qs as qs;
+
+/// An enum representing the possible values of a `ValueList`'s `item_type` field.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_lists/object#radar_value_list_object-item_type.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueListItemType {
+    CardBin,
+    CardFingerprint,
+    CaseSensitiveString,
+    Country,
+    Email,
+    IpAddress,
+    String,
+    #[serde(other)]
+    Other,
+}
+
+/// The resource representing a Stripe Radar value list.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_lists/object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ValueList {
+    pub id: String,
+    pub alias: String,
+    pub created: Timestamp,
+    pub created_by: String,
+    pub item_type: ValueListItemType,
+    pub list_items: List<ValueListItem>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub name: String,
+}
+
+impl Identifiable for ValueList {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The set of parameters that can be used when creating a value list.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_lists/create.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ValueListParams<'a> {
+    pub alias: &'a str, // (used to reference the list from a Radar rule)
+    pub name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<ValueListItemType>, // (defaults to `string`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when updating a value list.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_lists/update.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ValueListUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when listing value lists.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_lists/list.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ValueListListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contains: Option<&'a str>, // (only lists which contain this value)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl ValueList {
+    /// Creates a new value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/create.
+    pub fn create(client: &Client, params: ValueListParams) -> Result<ValueList, Error> {
+        client.post("/radar/value_lists", params)
+    }
+
+    /// Retrieves the details of a value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/retrieve.
+    pub fn retrieve(client: &Client, value_list_id: &str) -> Result<ValueList, Error> {
+        client.get(&format!("/radar/value_lists/{}", value_list_id))
+    }
+
+    /// Updates a value list's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/update.
+    pub fn update(
+        client: &Client,
+        value_list_id: &str,
+        params: ValueListUpdateParams,
+    ) -> Result<ValueList, Error> {
+        client.post(&format!("/radar/value_lists/{}", value_list_id), params)
+    }
+
+    /// Deletes a value list, which must not be referenced by any Radar rules.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/delete.
+    pub fn delete(client: &Client, value_list_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/radar/value_lists/{}", value_list_id))
+    }
+
+    /// Lists all value lists.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/list.
+    pub fn list(client: &Client, params: ValueListListParams) -> Result<List<ValueList>, Error> {
+        client.get(&format!("/radar/value_lists?{}", qs::to_string(&params)?))
+    }
+}
+
+/// The resource representing an item of a Stripe Radar value list.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_list_items/object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ValueListItem {
+    pub id: String,
+    pub created: Timestamp,
+    pub created_by: String,
+    pub livemode: bool,
+    pub value: String,
+    pub value_list: String,
+}
+
+impl Identifiable for ValueListItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The set of parameters that can be used when adding an item to a value list.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_list_items/create.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ValueListItemParams<'a> {
+    pub value: &'a str,
+    pub value_list: &'a str,
+}
+
+/// The set of parameters that can be used when listing the items of a value list.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_list_items/list.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ValueListItemListParams<'a> {
+    pub value_list: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<&'a str>,
+}
+
+impl ValueListItem {
+    /// Adds an item to a value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_list_items/create.
+    pub fn create(client: &Client, params: ValueListItemParams) -> Result<ValueListItem, Error> {
+        client.post("/radar/value_list_items", params)
+    }
+
+    /// Retrieves the details of a value list item.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_list_items/retrieve.
+    pub fn retrieve(client: &Client, item_id: &str) -> Result<ValueListItem, Error> {
+        client.get(&format!("/radar/value_list_items/{}", item_id))
+    }
+
+    /// Removes an item from its value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_list_items/delete.
+    pub fn delete(client: &Client, item_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/radar/value_list_items/{}", item_id))
+    }
+
+    /// Lists the items of a value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_list_items/list.
+    pub fn list(
+        client: &Client,
+        params: ValueListItemListParams,
+    ) -> Result<List<ValueListItem>, Error> {
+        client.get(&format!("/radar/value_list_items?{}", qs::to_string(&params)?))
+    }
+}
@@ -0,0 +1,101 @@
+//! Webhook support for [actix-web](https://actix.rs) handlers.
+//!
+//! The endpoint secret is provided through the extractor's config:
+//!
+//! ```rust,ignore
+//! fn webhook(event: VerifiedEvent) -> HttpResponse {
+//!     println!("{:?}", event.0.event_type);
+//!     HttpResponse::Ok().finish()
+//! }
+//!
+//! let app = App::new().resource("/webhooks", |r| {
+//!     r.method(http::Method::POST).with_config(webhook, |cfg| {
+//!         cfg.0.secret("whsec_...");
+//!     })
+//! });
+//! ```
+
+use actix_web::error::InternalError;
+use actix_web::{Error, FromRequest, HttpMessage, HttpRequest, HttpResponse};
+use futures::Future;
+use resources::Event;
+use super::{verify, SIGNATURE_HEADER};
+
+/// Configuration for the `VerifiedEvent` extractor.
+#[derive(Clone, Debug, Default)]
+pub struct WebhookConfig {
+    secret: String,
+}
+
+impl WebhookConfig {
+    /// Sets the endpoint secret used to verify webhook signatures.
+    pub fn secret<Str: Into<String>>(&mut self, secret: Str) -> &mut Self {
+        self.secret = secret.into();
+        self
+    }
+}
+
+/// An extractor for an `Event` whose `Stripe-Signature` has been verified.
+///
+/// Rejects the request with `400 Bad Request` if the signature can't be verified.
+#[derive(Clone, Debug)]
+pub struct VerifiedEvent(pub Event);
+
+impl<S: 'static> FromRequest<S> for VerifiedEvent {
+    type Config = WebhookConfig;
+    type Result = Box<dyn Future<Item = Self, Error = Error>>;
+
+    fn from_request(req: &HttpRequest<S>, cfg: &Self::Config) -> Self::Result {
+        let secret = cfg.secret.clone();
+        let signature = req.headers()
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        Box::new(req.body().from_err().and_then(move |body| {
+            verify(&body, signature.as_deref(), &secret)
+                .map(VerifiedEvent)
+                .map_err(|err| {
+                    let response = HttpResponse::BadRequest().body(err.to_string());
+                    InternalError::from_response(err, response).into()
+                })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::{Body, Error, FromRequest};
+    use resources::EventType;
+    use super::{VerifiedEvent, WebhookConfig};
+    use webhooks::tests::{sign, PAYLOAD, SECRET};
+    use webhooks::SIGNATURE_HEADER;
+
+    #[test]
+    fn extract_verified_event() {
+        let mut config = WebhookConfig::default();
+        config.secret(SECRET);
+        let VerifiedEvent(event) = TestRequest::with_header(SIGNATURE_HEADER, sign(PAYLOAD, SECRET))
+            .set_payload(PAYLOAD)
+            .run_async_result(|req| VerifiedEvent::from_request(req, &config))
+            .unwrap();
+        assert_eq!(event.event_type, EventType::PlanCreated);
+    }
+
+    #[test]
+    fn reject_bad_request() {
+        let mut config = WebhookConfig::default();
+        config.secret(SECRET);
+        let err = TestRequest::with_header(SIGNATURE_HEADER, sign(PAYLOAD, "whsec_other_secret"))
+            .set_payload(PAYLOAD)
+            .run_async_result::<_, _, VerifiedEvent, Error>(|req| VerifiedEvent::from_request(req, &config))
+            .unwrap_err();
+        let response = err.as_response_error().error_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        match *response.body() {
+            Body::Binary(ref body) => assert_eq!(body.as_ref(), &b"error comparing signatures"[..]),
+            ref other => panic!("expected a binary body; was {:?}", other),
+        }
+    }
+}
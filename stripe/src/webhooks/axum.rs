@@ -0,0 +1,107 @@
+//! Webhook support for [axum](https://github.com/tokio-rs/axum) handlers.
+//!
+//! The endpoint secret is read from the router's state through `FromRef`:
+//!
+//! ```rust,ignore
+//! #[derive(Clone)]
+//! struct AppState {
+//!     webhook_secret: String,
+//! }
+//!
+//! impl FromRef<AppState> for WebhookSecret {
+//!     fn from_ref(state: &AppState) -> Self {
+//!         WebhookSecret(state.webhook_secret.clone())
+//!     }
+//! }
+//!
+//! async fn webhook(VerifiedEvent(event): VerifiedEvent) {
+//!     println!("{:?}", event.event_type);
+//! }
+//!
+//! let app = Router::new().route("/webhooks", post(webhook)).with_state(state);
+//! ```
+
+use axum::body::Bytes;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::StatusCode;
+use futures_util::FutureExt;
+use resources::Event;
+use std::future::Future;
+use super::{verify, SIGNATURE_HEADER};
+
+/// The endpoint secret used to verify webhook signatures.
+#[derive(Clone, Debug)]
+pub struct WebhookSecret(pub String);
+
+/// An extractor for an `Event` whose `Stripe-Signature` has been verified.
+///
+/// Rejects the request with `400 Bad Request` if the signature can't be verified.
+#[derive(Clone, Debug)]
+pub struct VerifiedEvent(pub Event);
+
+impl<S> FromRequest<S> for VerifiedEvent
+where
+    S: Send + Sync,
+    WebhookSecret: FromRef<S>,
+{
+    type Rejection = (StatusCode, String);
+
+    fn from_request(
+        req: Request,
+        state: &S,
+    ) -> impl Future<Output = Result<Self, Self::Rejection>> + Send {
+        let WebhookSecret(secret) = WebhookSecret::from_ref(state);
+        let signature = req.headers()
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        Bytes::from_request(req, state).map(move |body| {
+            let body = body.map_err(|rejection| (rejection.status(), rejection.body_text()))?;
+            verify(&body, signature.as_deref(), &secret)
+                .map(VerifiedEvent)
+                .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::{FromRequest, Request};
+    use axum::http::StatusCode;
+    use futures_util::FutureExt;
+    use resources::EventType;
+    use super::{VerifiedEvent, WebhookSecret};
+    use webhooks::tests::{sign, PAYLOAD, SECRET};
+    use webhooks::SIGNATURE_HEADER;
+
+    fn request(signature: String) -> Request {
+        Request::post("/webhooks")
+            .header(SIGNATURE_HEADER, signature)
+            .body(Body::from(PAYLOAD))
+            .unwrap()
+    }
+
+    #[test]
+    fn extract_verified_event() {
+        let state = WebhookSecret(SECRET.to_string());
+        let req = request(sign(PAYLOAD, SECRET));
+        let VerifiedEvent(event) = VerifiedEvent::from_request(req, &state)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.event_type, EventType::PlanCreated);
+    }
+
+    #[test]
+    fn reject_bad_request() {
+        let state = WebhookSecret(SECRET.to_string());
+        let req = request(sign(PAYLOAD, "whsec_other_secret"));
+        let (status, message) = VerifiedEvent::from_request(req, &state)
+            .now_or_never()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(message, "error comparing signatures");
+    }
+}
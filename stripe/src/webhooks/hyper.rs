@@ -0,0 +1,74 @@
+//! Webhook support for [hyper](https://hyper.rs) services.
+//!
+//! ```rust,ignore
+//! fn webhook(req: Request<Body>) -> impl Future<Item = Response<Body>, Error = hyper::Error> {
+//!     stripe::webhooks::hyper::construct_event(req, "whsec_...")
+//!         .map(|event| {
+//!             println!("{:?}", event.event_type);
+//!             Response::new(Body::empty())
+//!         })
+//!         .or_else(|rejection| Ok(rejection))
+//! }
+//! ```
+
+use futures::{future, Future, Stream};
+use hyper::{Body, Request, Response, StatusCode};
+use resources::Event;
+use std::fmt::Display;
+use super::{verify, SIGNATURE_HEADER};
+
+/// Reads the body of a webhook request and verifies it against its `Stripe-Signature` header.
+///
+/// If the request can't be verified, the future fails with a `400 Bad Request`
+/// response describing the failure, which can be returned to Stripe as-is.
+pub fn construct_event(
+    req: Request<Body>,
+    secret: &str,
+) -> impl Future<Item = Event, Error = Response<Body>> {
+    let secret = secret.to_string();
+    let signature = req.headers()
+        .get(SIGNATURE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+    req.into_body().concat2().map_err(bad_request).and_then(move |body| {
+        future::result(verify(&body, signature.as_deref(), &secret).map_err(bad_request))
+    })
+}
+
+fn bad_request<E: Display>(err: E) -> Response<Body> {
+    let mut response = Response::new(Body::from(err.to_string()));
+    *response.status_mut() = StatusCode::BAD_REQUEST;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{Future, Stream};
+    use hyper::{Body, Request, StatusCode};
+    use resources::EventType;
+    use super::construct_event;
+    use webhooks::tests::{sign, PAYLOAD, SECRET};
+    use webhooks::SIGNATURE_HEADER;
+
+    #[test]
+    fn construct_verified_event() {
+        let req = Request::post("/webhooks")
+            .header(SIGNATURE_HEADER, sign(PAYLOAD, SECRET))
+            .body(Body::from(PAYLOAD))
+            .unwrap();
+        let event = construct_event(req, SECRET).wait().unwrap();
+        assert_eq!(event.event_type, EventType::PlanCreated);
+    }
+
+    #[test]
+    fn respond_bad_request() {
+        let req = Request::post("/webhooks")
+            .header(SIGNATURE_HEADER, sign(PAYLOAD, "whsec_other_secret"))
+            .body(Body::from(PAYLOAD))
+            .unwrap();
+        let response = construct_event(req, SECRET).wait().unwrap_err();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = response.into_body().concat2().wait().unwrap();
+        assert_eq!(&body[..], &b"error comparing signatures"[..]);
+    }
+}
@@ -0,0 +1,103 @@
+//! Helpers for receiving Stripe webhooks with popular web frameworks.
+//!
+//! Each integration is behind its own cargo feature (`webhooks-actix`, `webhooks-axum`
+//! and `webhooks-hyper`).  They read the raw request body and the `Stripe-Signature`
+//! header, verify them with `Webhook::construct_event`, and either yield the verified
+//! `Event` or respond with `400 Bad Request` describing why verification failed.
+
+use error::WebhookError;
+use resources::{Event, Webhook};
+
+#[cfg(feature = "webhooks-actix")]
+pub mod actix;
+#[cfg(feature = "webhooks-axum")]
+pub mod axum;
+#[cfg(feature = "webhooks-hyper")]
+pub mod hyper;
+
+/// The name of the header Stripe uses to sign webhook payloads.
+pub const SIGNATURE_HEADER: &str = "stripe-signature";
+
+/// Verifies a raw request body against the value of its `Stripe-Signature` header.
+fn verify(body: &[u8], signature: Option<&str>, secret: &str) -> Result<Event, WebhookError> {
+    let signature = signature.ok_or(WebhookError::BadSignature)?;
+    // N.B. a body which isn't valid utf-8 can't match the signature either way
+    let payload = String::from_utf8_lossy(body).into_owned();
+    Webhook::construct_event(payload, signature.to_string(), secret.to_string())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use chrono::Utc;
+    use error::WebhookError;
+    use hmac::{Hmac, Mac};
+    use resources::EventType;
+    use sha2::Sha256;
+    use super::verify;
+
+    pub const SECRET: &str = "whsec_test_secret";
+
+    pub const PAYLOAD: &str = r#"{
+        "id": "evt_1",
+        "object": "event",
+        "type": "plan.created",
+        "data": {
+            "object": {
+                "id": "gold",
+                "object": "plan",
+                "active": true,
+                "aggregate_usage": null,
+                "amount": 2000,
+                "billing_scheme": "per_unit",
+                "created": 1541000000,
+                "currency": "usd",
+                "interval": "month",
+                "interval_count": 1,
+                "livemode": false,
+                "metadata": {},
+                "nickname": null,
+                "product": "prod_1",
+                "tiers_mode": null,
+                "transform_usage": null,
+                "trial_period_days": null,
+                "usage_type": "licensed"
+            }
+        }
+    }"#;
+
+    /// Builds a `Stripe-Signature` header value for `payload`, signed with `secret`.
+    pub fn sign(payload: &str, secret: &str) -> String {
+        let timestamp = Utc::now().timestamp();
+        let mut mac = Hmac::<Sha256>::new_varkey(secret.as_bytes()).unwrap();
+        mac.input(format!("{}.{}", timestamp, payload).as_bytes());
+        let hex: String = mac.result().code().iter().map(|b| format!("{:02x}", b)).collect();
+        format!("t={},v1={}", timestamp, hex)
+    }
+
+    #[test]
+    fn verify_signed_payload() {
+        let signature = sign(PAYLOAD, SECRET);
+        let event = verify(PAYLOAD.as_bytes(), Some(&signature), SECRET).unwrap();
+        assert_eq!(event.event_type, EventType::PlanCreated);
+
+        // Rolled secrets produce multiple signatures, only one of which needs to match
+        let rolled = format!("{},v1={}", signature, "ab".repeat(32));
+        assert!(verify(PAYLOAD.as_bytes(), Some(&rolled), SECRET).is_ok());
+    }
+
+    #[test]
+    fn reject_bad_signatures() {
+        let signature = sign(PAYLOAD, "whsec_other_secret");
+        match verify(PAYLOAD.as_bytes(), Some(&signature), SECRET) {
+            Err(WebhookError::BadSignature) => {}
+            other => panic!("expected a bad signature; was {:?}", other),
+        }
+
+        for header in &[None, Some(""), Some("garbage"), Some("t=1"), Some("v1=zz,t=1")] {
+            match verify(PAYLOAD.as_bytes(), *header, SECRET) {
+                Err(WebhookError::BadSignature) => {}
+                other => panic!("expected a bad signature for {:?}; was {:?}", header, other),
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 #[macro_use]
 extern crate serde_json;
+extern crate reqwest;
 extern crate serde_qs;
 extern crate stripe;
 
@@ -277,3 +278,1391 @@ fn deserialize_customer_with_source() {
     let result = serde_json::from_value::<Customer>(example);
     assert!(result.is_ok(), "expected ok; was {:?}", result);
 }
+
+#[test]
+fn deserialize_balance() {
+    use stripe::{Balance, Currency};
+
+    let example = json!({
+      "object": "balance",
+      "available": [
+        {
+          "amount": 2217713,
+          "currency": "usd",
+          "source_types": {
+            "bank_account": 0,
+            "card": 2217713
+          }
+        }
+      ],
+      "connect_reserved": [
+        {
+          "amount": 0,
+          "currency": "usd"
+        }
+      ],
+      "livemode": false,
+      "pending": [
+        {
+          "amount": -3250,
+          "currency": "usd",
+          "source_types": {
+            "bank_account": 0,
+            "card": -3250
+          }
+        }
+      ]
+    });
+    let balance = serde_json::from_value::<Balance>(example).unwrap();
+    assert_eq!(balance.available[0].amount, 2217713);
+    assert_eq!(balance.available[0].currency, Currency::USD);
+    assert_eq!(balance.available[0].source_types.as_ref().and_then(|s| s.card), Some(2217713));
+    assert!(balance.connect_reserved[0].source_types.is_none());
+    assert_eq!(balance.pending[0].amount, -3250);
+}
+
+#[test]
+fn serialize_balance_transaction_list_params() {
+    use stripe::{BalanceTransactionListParams, BalanceTransactionType, Currency, RangeQuery};
+
+    let mut params = BalanceTransactionListParams::default();
+    params.payout = Some("po_1234");
+    params.transaction_type = Some(BalanceTransactionType::Charge);
+    params.currency = Some(Currency::USD);
+    params.available_on = Some(RangeQuery::gte(1501598702));
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "available_on[gte]=1501598702&currency=usd&payout=po_1234&type=charge");
+}
+
+#[test]
+fn deserialize_balance_transactions() {
+    use stripe::{BalanceTransaction, BalanceTransactionSource, BalanceTransactionType, List};
+
+    let example = json!({
+      "object": "list",
+      "data": [
+        {
+          "id": "txn_1",
+          "object": "balance_transaction",
+          "amount": 10000,
+          "available_on": 1542931200,
+          "created": 1542400000,
+          "currency": "usd",
+          "description": null,
+          "fee": 320,
+          "fee_details": [
+            {
+              "amount": 320,
+              "application": null,
+              "currency": "usd",
+              "description": "Stripe processing fees",
+              "type": "stripe_fee"
+            }
+          ],
+          "net": 9680,
+          "source": "ch_1",
+          "status": "available",
+          "type": "charge"
+        },
+        {
+          "id": "txn_2",
+          "object": "balance_transaction",
+          "amount": -2500,
+          "available_on": 1542931200,
+          "created": 1542500000,
+          "currency": "usd",
+          "description": "REFUND FOR CHARGE",
+          "fee": 0,
+          "fee_details": [],
+          "net": -2500,
+          "source": {
+            "id": "re_1",
+            "object": "refund",
+            "amount": 2500,
+            "balance_transaction": "txn_2",
+            "charge": "ch_1",
+            "created": 1542500000,
+            "currency": "usd",
+            "metadata": {},
+            "reason": null,
+            "receipt_number": null,
+            "status": "succeeded"
+          },
+          "status": "available",
+          "type": "refund"
+        }
+      ],
+      "has_more": false,
+      "url": "/v1/balance/history"
+    });
+    let list = serde_json::from_value::<List<BalanceTransaction>>(example).unwrap();
+    assert_eq!(list.data[0].source.as_ref().and_then(|s| s.id()), Some("ch_1"));
+    assert_eq!(list.data[1].transaction_type, BalanceTransactionType::Refund);
+    assert_eq!(list.data[1].amount, -2500);
+    assert_eq!(list.data[1].net, -2500);
+    match list.data[1].source {
+        Some(BalanceTransactionSource::Refund(ref refund)) => assert_eq!(refund.charge.id(), "ch_1"),
+        ref other => panic!("expected an expanded refund; was {:?}", other),
+    }
+}
+
+#[test]
+fn deserialize_balance_transaction_without_source() {
+    use stripe::{BalanceTransaction, BalanceTransactionType};
+
+    let example = json!({
+      "id": "txn_3",
+      "object": "balance_transaction",
+      "amount": -1500,
+      "available_on": 1542931200,
+      "created": 1542600000,
+      "currency": "usd",
+      "description": "Billing - Usage Fee",
+      "fee": 0,
+      "fee_details": [],
+      "net": -1500,
+      "source": null,
+      "status": "available",
+      "type": "stripe_fee"
+    });
+    let txn = serde_json::from_value::<BalanceTransaction>(example).unwrap();
+    assert!(txn.source.is_none());
+    assert_eq!(txn.transaction_type, BalanceTransactionType::StripeFee);
+    assert_eq!(txn.net, -1500);
+}
+
+#[test]
+fn deserialize_balance_transaction_with_unexpected_source() {
+    use stripe::{BalanceTransaction, BalanceTransactionSource};
+
+    let example = json!({
+      "id": "txn_4",
+      "object": "balance_transaction",
+      "amount": -9680,
+      "available_on": 1543000000,
+      "created": 1543000000,
+      "currency": "usd",
+      "description": "STRIPE PAYOUT",
+      "fee": 0,
+      "fee_details": [],
+      "net": -9680,
+      "source": {
+        "id": "po_1",
+        "object": "payout",
+        "amount": 9680,
+        "status": { "unexpected": true }
+      },
+      "status": "available",
+      "type": "payout"
+    });
+    let txn = serde_json::from_value::<BalanceTransaction>(example).unwrap();
+    match txn.source {
+        Some(BalanceTransactionSource::Other(ref value)) => assert_eq!(value["object"], "payout"),
+        ref other => panic!("expected an unparsed source; was {:?}", other),
+    }
+    assert_eq!(txn.source.as_ref().and_then(|s| s.id()), Some("po_1"));
+}
+
+#[test]
+fn deserialize_dispute() {
+    use stripe::{Dispute, DisputeReason, DisputeStatus};
+
+    let example = json!({
+      "id": "dp_1",
+      "object": "dispute",
+      "amount": 1000,
+      "balance_transactions": [],
+      "charge": "ch_1",
+      "created": 1542400000,
+      "currency": "usd",
+      "evidence": {
+        "customer_email_address": "jenny.rosen@example.com",
+        "uncategorized_file": null
+      },
+      "evidence_details": {
+        "due_by": 1543190399,
+        "has_evidence": false,
+        "past_due": false,
+        "submission_count": 0
+      },
+      "is_charge_refundable": false,
+      "livemode": false,
+      "metadata": {},
+      "reason": "product_not_received",
+      "status": "needs_response"
+    });
+    let dispute = serde_json::from_value::<Dispute>(example).unwrap();
+    assert_eq!(dispute.reason, DisputeReason::ProductNotReceived);
+    assert_eq!(dispute.status, DisputeStatus::NeedsResponse);
+    assert_eq!(dispute.evidence.customer_email_address.as_deref(), Some("jenny.rosen@example.com"));
+}
+
+#[test]
+fn serialize_dispute_params() {
+    use stripe::{DisputeEvidenceObject, DisputeListParams, DisputeParams, RangeQuery};
+
+    let mut evidence = DisputeEvidenceObject::default();
+    evidence.shipping_carrier = Some("UPS".to_string());
+    evidence.shipping_tracking_number = Some("1Z999".to_string());
+    let mut params = DisputeParams::default();
+    params.evidence = Some(evidence);
+    params.submit = Some(false);
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "evidence[shipping_carrier]=UPS&evidence[shipping_tracking_number]=1Z999&submit=false");
+
+    let mut params = DisputeListParams::default();
+    params.charge = Some("ch_1".to_string());
+    params.created = Some(RangeQuery::gt(1542400000));
+    params.starting_after = Some("dp_1");
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "charge=ch_1&created[gt]=1542400000&starting_after=dp_1");
+}
+
+#[test]
+fn deserialize_file() {
+    use stripe::{File, FilePurpose};
+
+    let example = json!({
+      "id": "file_1",
+      "object": "file",
+      "created": 1542400000,
+      "filename": "receipt.pdf",
+      "purpose": "dispute_evidence",
+      "size": 9863,
+      "title": null,
+      "type": "pdf",
+      "url": null
+    });
+    let file = serde_json::from_value::<File>(example).unwrap();
+    assert_eq!(file.purpose, FilePurpose::DisputeEvidence);
+    assert_eq!(file.purpose.to_string(), "dispute_evidence");
+    assert!(file.url.is_none());
+}
+
+#[test]
+fn serialize_file_upload_form() {
+    use reqwest::multipart::Part;
+    use stripe::{File, FilePurpose};
+
+    let form = File::upload_form(FilePurpose::DisputeEvidence, "receipt.pdf", Part::bytes(&b"%PDF-1.4"[..]));
+    let parts = format!("{:?}", form);
+    assert!(parts.contains(r#"("purpose", Part { value: Body { kind: b"dispute_evidence" }, mime: None, file_name: None"#), "{}", parts);
+    assert!(parts.contains(r#"("file", Part { value: Body { kind: b"%PDF-1.4" }, mime: None, file_name: Some("receipt.pdf")"#), "{}", parts);
+}
+
+#[test]
+fn deserialize_transfer() {
+    use stripe::{AccountId, SourceTransactionId, Transfer};
+
+    let mut example = json!({
+      "id": "tr_1",
+      "object": "transfer",
+      "amount": 1100,
+      "amount_reversed": 100,
+      "balance_transaction": "txn_1",
+      "created": 1542400000,
+      "currency": "usd",
+      "description": null,
+      "destination": "acct_1",
+      "destination_payment": "py_1",
+      "livemode": false,
+      "metadata": {},
+      "reversals": {
+        "object": "list",
+        "data": [
+          {
+            "id": "trr_1",
+            "object": "transfer_reversal",
+            "amount": 100,
+            "balance_transaction": "txn_2",
+            "created": 1542500000,
+            "currency": "usd",
+            "metadata": {},
+            "transfer": "tr_1"
+          }
+        ],
+        "has_more": false,
+        "url": "/v1/transfers/tr_1/reversals"
+      },
+      "reversed": false,
+      "source_transaction": "ch_1",
+      "source_type": "card",
+      "transfer_group": "ORDER_95"
+    });
+    let transfer = serde_json::from_value::<Transfer>(example.clone()).unwrap();
+    assert_eq!(transfer.destination, "acct_1".parse::<AccountId>().unwrap());
+    match transfer.source_transaction {
+        Some(SourceTransactionId::Charge(ref id)) => assert_eq!(id.as_str(), "ch_1"),
+        ref other => panic!("expected a charge id; was {:?}", other),
+    }
+    assert_eq!(transfer.reversals.data[0].transfer, transfer.id);
+
+    // Transfers funded by a payment on a connected account have a `py_` source transaction
+    example["source_transaction"] = json!("py_1");
+    let transfer = serde_json::from_value::<Transfer>(example).unwrap();
+    match transfer.source_transaction {
+        Some(SourceTransactionId::Payment(ref id)) => assert_eq!(id.as_str(), "py_1"),
+        ref other => panic!("expected a payment id; was {:?}", other),
+    }
+}
+
+#[test]
+fn serialize_transfer_params() {
+    use stripe::{Currency, TransferParams};
+
+    let params = TransferParams {
+        amount: 1000,
+        currency: Currency::USD,
+        destination: "acct_1".parse().unwrap(),
+        description: None,
+        metadata: None,
+        source_transaction: Some("ch_1".parse().unwrap()),
+        transfer_group: Some("ORDER_95"),
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "amount=1000&currency=usd&destination=acct_1&source_transaction=ch_1&transfer_group=ORDER_95");
+
+    let params = TransferParams { source_transaction: Some("py_1".parse().unwrap()), ..params };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "amount=1000&currency=usd&destination=acct_1&source_transaction=py_1&transfer_group=ORDER_95");
+}
+
+#[test]
+fn serialize_transfer_reversal_update_params() {
+    use stripe::{Metadata, TransferReversalUpdateParams};
+
+    let mut metadata = Metadata::new();
+    metadata.insert("order_id".to_string(), "6735".to_string());
+    let params = TransferReversalUpdateParams { metadata: Some(metadata) };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "metadata[order_id]=6735");
+}
+
+#[test]
+fn deserialize_account() {
+    use stripe::{Account, AccountType, LegalEntityType, LegalEntityVerificationStatus};
+
+    let example = json!({
+      "id": "acct_1",
+      "object": "account",
+      "business_name": null,
+      "business_url": null,
+      "charges_enabled": true,
+      "country": "US",
+      "debit_negative_balances": false,
+      "default_currency": "usd",
+      "details_submitted": false,
+      "display_name": null,
+      "email": "jenny.rosen@example.com",
+      "legal_entity": {
+        "additional_owners": [],
+        "address": {
+          "city": null,
+          "country": "US",
+          "line1": null,
+          "line2": null,
+          "postal_code": null,
+          "state": null
+        },
+        "business_name": null,
+        "business_tax_id_provided": false,
+        "dob": { "day": null, "month": null, "year": null },
+        "first_name": "Jenny",
+        "last_name": "Rosen",
+        "personal_address": null,
+        "personal_id_number_provided": false,
+        "ssn_last_4_provided": true,
+        "type": "individual",
+        "verification": {
+          "details": null,
+          "details_code": null,
+          "document": null,
+          "status": "unverified"
+        }
+      },
+      "metadata": {},
+      "payouts_enabled": false,
+      "statement_descriptor": null,
+      "support_email": null,
+      "support_phone": null,
+      "timezone": "Etc/UTC",
+      "type": "custom",
+      "verification": {
+        "disabled_reason": "fields_needed",
+        "due_by": null,
+        "fields_needed": ["legal_entity.dob.day", "tos_acceptance.date"]
+      }
+    });
+    let account = serde_json::from_value::<Account>(example).unwrap();
+    assert!(account.charges_enabled);
+    assert_eq!(account.account_type, Some(AccountType::Custom));
+    let legal_entity = account.legal_entity.unwrap();
+    assert_eq!(legal_entity.legal_entity_type, Some(LegalEntityType::Individual));
+    assert!(legal_entity.ssn_last_4_provided);
+    assert_eq!(legal_entity.verification.unwrap().status, LegalEntityVerificationStatus::Unverified);
+    assert_eq!(account.verification.unwrap().fields_needed.len(), 2);
+}
+
+#[test]
+fn serialize_account_params() {
+    use stripe::{AccountParams, AccountType, LegalEntityParams, LegalEntityType};
+
+    let mut legal_entity = LegalEntityParams::default();
+    legal_entity.legal_entity_type = Some(LegalEntityType::Company);
+    legal_entity.business_name = Some("RocketRides");
+    let mut params = AccountParams::default();
+    params.account_type = Some(AccountType::Custom);
+    params.country = Some("US");
+    params.legal_entity = Some(legal_entity);
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "country=US&legal_entity[business_name]=RocketRides&legal_entity[type]=company&type=custom");
+}
+
+#[test]
+fn serialize_authorize_url() {
+    use stripe::{AuthorizeUrlParams, OAuthScope, StripeUserParams};
+
+    let mut stripe_user = StripeUserParams::default();
+    stripe_user.email = Some("jenny@example.com");
+    let mut params = AuthorizeUrlParams::default();
+    params.client_id = "ca_1";
+    params.scope = Some(OAuthScope::ReadWrite);
+    params.state = Some("csrf");
+    params.stripe_user = Some(stripe_user);
+    assert_eq!(
+        urldecode(params.url().unwrap()),
+        "https://connect.stripe.com/oauth/authorize?response_type=code&client_id=ca_1&scope=read_write&state=csrf&stripe_user[email]=jenny%40example.com"
+    );
+}
+
+#[test]
+fn deserialize_oauth() {
+    use stripe::{OAuthError, OAuthErrorCode, OAuthScope, OAuthToken};
+
+    let example = json!({
+      "access_token": "sk_test_1",
+      "livemode": false,
+      "refresh_token": "rt_1",
+      "scope": "read_write",
+      "stripe_publishable_key": "pk_test_1",
+      "stripe_user_id": "acct_1",
+      "token_type": "bearer"
+    });
+    let token = serde_json::from_value::<OAuthToken>(example).unwrap();
+    assert_eq!(token.scope, OAuthScope::ReadWrite);
+    assert_eq!(token.stripe_user_id.as_str(), "acct_1");
+
+    let example = json!({
+      "error": "invalid_grant",
+      "error_description": "Authorization code does not exist: ac_1"
+    });
+    let error = serde_json::from_value::<OAuthError>(example).unwrap();
+    assert_eq!(error.code, OAuthErrorCode::InvalidGrant);
+
+    let error = serde_json::from_value::<OAuthError>(json!({ "error": "invalid_something" })).unwrap();
+    assert_eq!(error.code, OAuthErrorCode::Unknown);
+}
+
+#[test]
+fn deserialize_external_accounts() {
+    use stripe::{ExternalAccount, List};
+
+    let example = json!({
+      "object": "list",
+      "data": [
+        {
+          "id": "ba_1",
+          "object": "bank_account",
+          "account": "acct_1",
+          "account_holder_name": "Jane Austen",
+          "account_holder_type": "individual",
+          "bank_name": "STRIPE TEST BANK",
+          "country": "US",
+          "currency": "usd",
+          "default_for_currency": true,
+          "fingerprint": "1JWtPxqbdX5Gamtc",
+          "last4": "6789",
+          "metadata": {},
+          "routing_number": "110000000",
+          "status": "new"
+        },
+        {
+          "id": "card_1",
+          "object": "card",
+          "account": "acct_1",
+          "address_city": null,
+          "address_country": null,
+          "address_line1": null,
+          "address_line1_check": null,
+          "address_line2": null,
+          "address_state": null,
+          "address_zip": null,
+          "address_zip_check": null,
+          "available_payout_methods": ["standard", "instant"],
+          "brand": "Visa",
+          "country": "US",
+          "currency": "usd",
+          "cvc_check": null,
+          "default_for_currency": false,
+          "dynamic_last4": null,
+          "exp_month": 8,
+          "exp_year": 2020,
+          "fingerprint": "Xt5EWLLDS7FJjR1c",
+          "funding": "debit",
+          "last4": "5556",
+          "metadata": {},
+          "name": null,
+          "tokenization_method": null
+        }
+      ],
+      "has_more": false,
+      "url": "/v1/accounts/acct_1/external_accounts"
+    });
+    let list = serde_json::from_value::<List<ExternalAccount>>(example).unwrap();
+    match list.data[0] {
+        ExternalAccount::BankAccount(ref account) => assert_eq!(account.default_for_currency, Some(true)),
+        ref other => panic!("expected a bank account; was {:?}", other),
+    }
+    match list.data[1] {
+        ExternalAccount::Card(ref card) => assert_eq!(card.last4, "5556"),
+        ref other => panic!("expected a card; was {:?}", other),
+    }
+}
+
+#[test]
+fn serialize_external_account_params() {
+    use stripe::{ExternalAccountParams, ExternalAccountTokenId};
+
+    let params = ExternalAccountParams {
+        external_account: "btok_1".parse().unwrap(),
+        default_for_currency: Some(true),
+        metadata: None,
+    };
+    match params.external_account {
+        ExternalAccountTokenId::BankToken(ref token) => assert_eq!(token.as_str(), "btok_1"),
+        ref other => panic!("expected a bank account token; was {:?}", other),
+    }
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "external_account=btok_1&default_for_currency=true");
+
+    let params = ExternalAccountParams {
+        external_account: "tok_1".parse().unwrap(),
+        default_for_currency: None,
+        metadata: None,
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "external_account=tok_1");
+}
+
+#[test]
+fn serialize_account_link_params() {
+    use stripe::{AccountLinkParams, AccountLinkType};
+
+    let params = AccountLinkParams {
+        account: "acct_1".parse().unwrap(),
+        refresh_url: "https://example.com/reauth",
+        return_url: "https://example.com/return",
+        link_type: AccountLinkType::AccountOnboarding,
+        collect: None,
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(
+        encoded,
+        "account=acct_1&refresh_url=https%3A%2F%2Fexample.com%2Freauth&return_url=https%3A%2F%2Fexample.com%2Freturn&type=account_onboarding"
+    );
+}
+
+#[test]
+fn deserialize_application_fee() {
+    use stripe::{ApplicationFee, Expandable};
+
+    let example = json!({
+      "id": "fee_1",
+      "object": "application_fee",
+      "account": "acct_1",
+      "amount": 100,
+      "amount_refunded": 25,
+      "application": "ca_1",
+      "balance_transaction": "txn_1",
+      "charge": "py_1",
+      "created": 1542400000,
+      "currency": "usd",
+      "livemode": false,
+      "originating_transaction": "ch_1",
+      "refunded": false,
+      "refunds": {
+        "object": "list",
+        "data": [
+          {
+            "id": "fr_1",
+            "object": "fee_refund",
+            "amount": 25,
+            "balance_transaction": null,
+            "created": 1542500000,
+            "currency": "usd",
+            "fee": "fee_1",
+            "metadata": {}
+          }
+        ],
+        "has_more": false,
+        "url": "/v1/application_fees/fee_1/refunds"
+      }
+    });
+    let fee = serde_json::from_value::<ApplicationFee>(example).unwrap();
+    assert_eq!(fee.refunds.data[0].fee, "fee_1");
+    match fee.originating_transaction {
+        Some(Expandable::Id(ref id)) => assert_eq!(id, "ch_1"),
+        ref other => panic!("expected an unexpanded charge id; was {:?}", other),
+    }
+}
+
+#[test]
+fn serialize_application_fee_refund_update_params() {
+    use stripe::{ApplicationFeeRefundUpdateParams, Metadata};
+
+    let mut metadata = Metadata::new();
+    metadata.insert("reason".to_string(), "duplicate".to_string());
+    let params = ApplicationFeeRefundUpdateParams { metadata: Some(metadata) };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "metadata[reason]=duplicate");
+}
+
+#[test]
+fn deserialize_sku() {
+    use stripe::{InventoryType, InventoryValue, Sku};
+
+    let example = json!({
+      "id": "sku_1",
+      "object": "sku",
+      "active": true,
+      "attributes": { "size": "Medium", "gender": "Unisex" },
+      "created": 1542400000,
+      "currency": "usd",
+      "image": null,
+      "inventory": { "quantity": null, "type": "bucket", "value": "limited" },
+      "livemode": false,
+      "metadata": {},
+      "package_dimensions": null,
+      "price": 1500,
+      "product": "prod_1",
+      "updated": 1542400000
+    });
+    let sku = serde_json::from_value::<Sku>(example).unwrap();
+    assert_eq!(sku.attributes["size"], "Medium");
+    assert_eq!(sku.inventory.inventory_type, InventoryType::Bucket);
+    assert_eq!(sku.inventory.value, Some(InventoryValue::Limited));
+}
+
+#[test]
+fn serialize_product_params() {
+    use stripe::{Inventory, InventoryType, ProductParams, ProductType, SkuParams};
+    use std::collections::HashMap;
+
+    let mut params = ProductParams::default();
+    params.name = Some("T-shirt");
+    params.product_type = Some(ProductType::Good);
+    params.attributes = Some(vec!["size"]);
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "attributes[0]=size&name=T-shirt&type=good");
+
+    let mut attributes = HashMap::new();
+    attributes.insert("size".to_string(), "Medium".to_string());
+    let mut params = SkuParams::default();
+    params.attributes = Some(attributes);
+    params.inventory = Some(Inventory {
+        quantity: Some(50),
+        inventory_type: InventoryType::Finite,
+        value: None,
+    });
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "attributes[size]=Medium&inventory[quantity]=50&inventory[type]=finite");
+}
+
+#[test]
+fn deserialize_order() {
+    use stripe::{DeliveryEstimateType, Order, OrderItemType, OrderStatus};
+
+    let example = json!({
+      "id": "or_1",
+      "object": "order",
+      "amount": 1700,
+      "amount_returned": null,
+      "application": null,
+      "application_fee": null,
+      "charge": null,
+      "created": 1542400000,
+      "currency": "usd",
+      "customer": null,
+      "email": "jenny.rosen@example.com",
+      "external_coupon_code": null,
+      "items": [
+        {
+          "object": "order_item",
+          "amount": 1500,
+          "currency": "usd",
+          "description": "T-shirt",
+          "parent": "sku_1",
+          "quantity": 1,
+          "type": "sku"
+        },
+        {
+          "object": "order_item",
+          "amount": 200,
+          "currency": "usd",
+          "description": "Free shipping",
+          "parent": "ship_free",
+          "quantity": null,
+          "type": "shipping"
+        }
+      ],
+      "livemode": false,
+      "metadata": {},
+      "returns": {
+        "object": "list",
+        "data": [],
+        "has_more": false,
+        "total_count": 0,
+        "url": "/v1/order_returns?order=or_1"
+      },
+      "selected_shipping_method": "ship_free",
+      "shipping": {
+        "address": {
+          "city": "Anytown",
+          "country": "US",
+          "line1": "1234 Main street",
+          "line2": null,
+          "postal_code": "123456",
+          "state": null
+        },
+        "carrier": null,
+        "name": "Jenny Rosen",
+        "phone": null,
+        "tracking_number": null
+      },
+      "shipping_methods": [
+        {
+          "id": "ship_free",
+          "amount": 200,
+          "currency": "usd",
+          "delivery_estimate": { "type": "range", "earliest": "2018-11-20", "latest": "2018-11-24" },
+          "description": "Free shipping"
+        }
+      ],
+      "status": "created",
+      "status_transitions": {},
+      "updated": 1542400000,
+      "upstream_id": null
+    });
+    let order = serde_json::from_value::<Order>(example).unwrap();
+    assert_eq!(order.status, OrderStatus::Created);
+    assert_eq!(order.items[0].item_type, OrderItemType::Sku);
+    assert_eq!(order.items[1].item_type, OrderItemType::Shipping);
+    let estimate = order.shipping_methods[0].delivery_estimate.as_ref().unwrap();
+    assert_eq!(estimate.estimate_type, DeliveryEstimateType::Range);
+    assert_eq!(estimate.latest.as_deref(), Some("2018-11-24"));
+}
+
+#[test]
+fn serialize_coupon_params() {
+    use stripe::{CouponDuration, CouponParams, Currency};
+
+    let mut params = CouponParams::percent_off(CouponDuration::Repeating, 25.5);
+    params.duration_in_months = Some(3);
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "duration=repeating&percent_off=25.5&duration_in_months=3");
+
+    let params = CouponParams::amount_off(CouponDuration::Once, 500, Currency::USD);
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "duration=once&amount_off=500&currency=usd");
+}
+
+#[test]
+fn deserialize_tiered_plan() {
+    use stripe::{BillingScheme, Plan, TiersMode, UpTo, UsageType};
+
+    let example = json!({
+      "id": "api_calls",
+      "object": "plan",
+      "active": true,
+      "aggregate_usage": "sum",
+      "amount": null,
+      "billing_scheme": "tiered",
+      "created": 1542400000,
+      "currency": "usd",
+      "interval": "month",
+      "interval_count": 1,
+      "livemode": false,
+      "metadata": {},
+      "nickname": null,
+      "product": "prod_1",
+      "tiers": [
+        { "amount": 10, "flat_amount": null, "up_to": 1000 },
+        { "amount": 5, "flat_amount": null, "up_to": null }
+      ],
+      "tiers_mode": "graduated",
+      "transform_usage": null,
+      "trial_period_days": null,
+      "usage_type": "metered"
+    });
+    let plan = serde_json::from_value::<Plan>(example).unwrap();
+    assert_eq!(plan.billing_scheme, BillingScheme::Tiered);
+    assert_eq!(plan.tiers_mode, Some(TiersMode::Graduated));
+    assert_eq!(plan.usage_type, UsageType::Metered);
+    assert_eq!(plan.product.id(), "prod_1");
+    let tiers = plan.tiers.unwrap();
+    assert_eq!(tiers[0].up_to, UpTo::Max(1000));
+    assert_eq!(tiers[1].up_to, UpTo::Inf);
+}
+
+#[test]
+fn serialize_plan_params() {
+    use stripe::{BillingScheme, Currency, PlanParams, PlanProduct, PlanProductParams, PlanTierParams, TiersMode, UpTo};
+
+    let mut params = PlanParams::new(Currency::USD, "month", PlanProduct::Id("prod_1"));
+    params.billing_scheme = Some(BillingScheme::Tiered);
+    params.tiers_mode = Some(TiersMode::Volume);
+    params.tiers = vec![
+        PlanTierParams { amount: Some(10), flat_amount: None, up_to: UpTo::Max(1000) },
+        PlanTierParams { amount: Some(5), flat_amount: None, up_to: UpTo::Inf },
+    ];
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(
+        encoded,
+        "currency=usd&interval=month&product=prod_1&billing_scheme=tiered\
+         &tiers[0][amount]=10&tiers[0][up_to]=1000&tiers[1][amount]=5&tiers[1][up_to]=inf\
+         &tiers_mode=volume"
+    );
+
+    let product = PlanProductParams { name: "Gold", ..Default::default() };
+    let mut params = PlanParams::new(Currency::USD, "year", PlanProduct::Inline(product));
+    params.amount = Some(20000);
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "currency=usd&interval=year&product[name]=Gold&amount=20000");
+}
+
+#[test]
+fn deserialize_invoice() {
+    use stripe::{Invoice, InvoiceBilling, InvoiceStatus};
+
+    let example = json!({
+      "id": "in_1",
+      "object": "invoice",
+      "amount_due": 2000,
+      "application_fee": null,
+      "attempt_count": 0,
+      "attempted": false,
+      "auto_advance": true,
+      "billing": "send_invoice",
+      "charge": null,
+      "currency": "usd",
+      "customer": "cus_1",
+      "date": 1542400000,
+      "description": null,
+      "discount": null,
+      "due_date": 1543000000,
+      "ending_balance": null,
+      "lines": {
+        "object": "list",
+        "data": [
+          {
+            "id": "ii_1",
+            "object": "line_item",
+            "amount": 2000,
+            "currency": "usd",
+            "description": "Consulting",
+            "discountable": true,
+            "livemode": false,
+            "metadata": {},
+            "period": { "start": 1542400000, "end": 1542400000 },
+            "plan": null,
+            "proration": false,
+            "quantity": 1,
+            "subscription": null,
+            "subscription_item": null,
+            "type": "invoiceitem"
+          }
+        ],
+        "has_more": false,
+        "total_count": 1,
+        "url": "/v1/invoices/in_1/lines"
+      },
+      "livemode": false,
+      "metadata": {},
+      "next_payment_attempt": null,
+      "number": "ABCD-0001",
+      "paid": false,
+      "period_end": 1542400000,
+      "period_start": 1542400000,
+      "receipt_number": null,
+      "starting_balance": 0,
+      "statement_descriptor": null,
+      "status": "open",
+      "subscription": null,
+      "subtotal": 2000,
+      "tax": null,
+      "tax_percent": null,
+      "total": 2000,
+      "webhooks_delivered_at": null
+    });
+    let invoice = serde_json::from_value::<Invoice>(example).unwrap();
+    assert_eq!(invoice.status, Some(InvoiceStatus::Open));
+    assert_eq!(invoice.billing, Some(InvoiceBilling::SendInvoice));
+    assert_eq!(invoice.lines.data[0].id, "ii_1");
+    assert_eq!(invoice.lines.url, "/v1/invoices/in_1/lines");
+}
+
+#[test]
+fn serialize_invoice_pay_params() {
+    use stripe::InvoicePayParams;
+
+    let params = InvoicePayParams {
+        paid_out_of_band: Some(true),
+        ..Default::default()
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "paid_out_of_band=true");
+
+    let params = InvoicePayParams {
+        source: Some("card_123".parse().unwrap()),
+        ..Default::default()
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "source=card_123");
+}
+
+#[test]
+fn serialize_invoice_item_params() {
+    use stripe::{Currency, InvoiceItemParams, Period};
+
+    let params = InvoiceItemParams {
+        customer: "cus_1",
+        currency: Currency::USD,
+        unit_amount: Some(250),
+        quantity: Some(4),
+        period: Some(Period { start: 1542400000, end: 1545000000 }),
+        tax_rates: vec!["txr_1"],
+        ..Default::default()
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(
+        encoded,
+        "customer=cus_1&currency=usd&period[start]=1542400000&period[end]=1545000000\
+         &quantity=4&tax_rates[0]=txr_1&unit_amount=250"
+    );
+}
+
+#[test]
+fn serialize_invoice_upcoming_params() {
+    use stripe::{BillingCycleAnchor, InvoiceListLinesParams, InvoiceUpcomingLinesParams,
+                 InvoiceUpcomingParams, SubscriptionItemParams};
+
+    let params = InvoiceUpcomingParams {
+        customer: "cus_1",
+        subscription: Some("sub_1"),
+        subscription_billing_cycle_anchor: Some(BillingCycleAnchor::Now),
+        subscription_items: vec![
+            SubscriptionItemParams {
+                id: Some("si_1"),
+                deleted: Some(true),
+                ..Default::default()
+            },
+            SubscriptionItemParams {
+                plan: Some("gold"),
+                quantity: Some(2),
+                ..Default::default()
+            },
+        ],
+        subscription_proration_date: Some(1542400000),
+        ..Default::default()
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(
+        encoded,
+        "customer=cus_1&subscription=sub_1&subscription_billing_cycle_anchor=now\
+         &subscription_items[0][id]=si_1&subscription_items[0][deleted]=true\
+         &subscription_items[1][plan]=gold&subscription_items[1][quantity]=2\
+         &subscription_proration_date=1542400000"
+    );
+
+    let params = InvoiceUpcomingLinesParams {
+        upcoming: InvoiceUpcomingParams {
+            customer: "cus_1",
+            subscription_plan: Some("gold"),
+            ..Default::default()
+        },
+        lines: InvoiceListLinesParams {
+            limit: Some(50),
+            starting_after: Some("ii_1"),
+            ..Default::default()
+        },
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "customer=cus_1&subscription_plan=gold&limit=50&starting_after=ii_1");
+}
+
+#[test]
+fn serialize_refund_params() {
+    use stripe::{RefundParams, RefundReason};
+
+    let params = RefundParams {
+        payment_intent: Some("pi_1".to_string()),
+        amount: Some(500),
+        expand: vec!["charge".to_string()],
+        reason: Some(RefundReason::RequestedByCustomer),
+        ..Default::default()
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "payment_intent=pi_1&amount=500&expand[0]=charge&reason=requested_by_customer");
+}
+
+#[test]
+fn deserialize_charge_list() {
+    use stripe::{Charge, ChargeStatus, List};
+
+    let example = json!({
+      "object": "list",
+      "data": [
+        {
+          "id": "ch_1DaQ3z2eZvKYlo2C1H9EpVaM",
+          "object": "charge",
+          "amount": 2000,
+          "amount_refunded": 500,
+          "application": null,
+          "application_fee": null,
+          "balance_transaction": "txn_1DaQ3z2eZvKYlo2CJUb8J1rD",
+          "captured": true,
+          "created": 1543242643,
+          "currency": "usd",
+          "customer": "cus_E2VU7Qh3C1IZt6",
+          "description": "Charge for jenny.rosen@example.com",
+          "destination": null,
+          "dispute": null,
+          "failure_code": null,
+          "failure_message": null,
+          "fraud_details": {},
+          "invoice": null,
+          "livemode": false,
+          "metadata": {},
+          "on_behalf_of": null,
+          "order": null,
+          "outcome": {
+            "network_status": "approved_by_network",
+            "reason": null,
+            "risk_level": "normal",
+            "risk_score": 32,
+            "seller_message": "Payment complete.",
+            "type": "authorized"
+          },
+          "paid": true,
+          "payment_intent": null,
+          "receipt_email": null,
+          "receipt_number": null,
+          "refunded": false,
+          "refunds": {
+            "object": "list",
+            "data": [
+              {
+                "id": "re_1DaQ4k2eZvKYlo2C3Xnwa7yS",
+                "object": "refund",
+                "amount": 500,
+                "balance_transaction": "txn_1DaQ4k2eZvKYlo2CCjcyBH2G",
+                "charge": "ch_1DaQ3z2eZvKYlo2C1H9EpVaM",
+                "created": 1543242690,
+                "currency": "usd",
+                "failure_balance_transaction": null,
+                "failure_reason": null,
+                "metadata": {},
+                "reason": "requested_by_customer",
+                "receipt_number": null,
+                "status": "succeeded"
+              }
+            ],
+            "has_more": false,
+            "total_count": 1,
+            "url": "/v1/charges/ch_1DaQ3z2eZvKYlo2C1H9EpVaM/refunds"
+          },
+          "review": null,
+          "shipping": null,
+          "source": {
+            "id": "card_1DaQ3y2eZvKYlo2CcHmgyrFn",
+            "object": "card",
+            "address_city": null,
+            "address_country": null,
+            "address_line1": null,
+            "address_line1_check": null,
+            "address_line2": null,
+            "address_state": null,
+            "address_zip": "42424",
+            "address_zip_check": "pass",
+            "brand": "Visa",
+            "country": "US",
+            "customer": "cus_E2VU7Qh3C1IZt6",
+            "cvc_check": "pass",
+            "dynamic_last4": null,
+            "exp_month": 4,
+            "exp_year": 2024,
+            "fingerprint": "Xt5EWLLDS7FJjR1c",
+            "funding": "credit",
+            "last4": "4242",
+            "metadata": {},
+            "name": null,
+            "tokenization_method": null
+          },
+          "source_transfer": null,
+          "statement_descriptor": null,
+          "status": "succeeded",
+          "transfer_group": null
+        }
+      ],
+      "has_more": true,
+      "url": "/v1/charges"
+    });
+    let list = serde_json::from_value::<List<Charge>>(example).unwrap();
+    assert!(list.has_more);
+    assert_eq!(list.url, "/v1/charges");
+    assert_eq!(list.data[0].status, ChargeStatus::Succeeded);
+    assert_eq!(list.data[0].amount_refunded, 500);
+    assert_eq!(list.data[0].refunds.data[0].amount, 500);
+    assert_eq!(list.data[0].refunds.data[0].charge.id(), list.data[0].id);
+}
+
+#[test]
+fn serialize_charge_list_params() {
+    use stripe::ChargeListParams;
+
+    let params = ChargeListParams {
+        customer: Some("cus_E2VU7Qh3C1IZt6".parse().unwrap()),
+        limit: Some(10),
+        ..Default::default()
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "customer=cus_E2VU7Qh3C1IZt6&limit=10");
+}
+
+#[test]
+fn deserialize_review() {
+    use stripe::{Review, ReviewReason};
+
+    let example = json!({
+      "id": "prv_1",
+      "object": "review",
+      "charge": "ch_1",
+      "created": 1542400000,
+      "livemode": false,
+      "open": true,
+      "payment_intent": null,
+      "reason": "refunded_as_fraud"
+    });
+    let review = serde_json::from_value::<Review>(example).unwrap();
+    assert!(review.open);
+    assert_eq!(review.reason, ReviewReason::RefundedAsFraud);
+}
+
+#[test]
+fn deserialize_value_list() {
+    use stripe::{ValueList, ValueListItemType};
+
+    let example = json!({
+      "id": "rsl_1",
+      "object": "radar.value_list",
+      "alias": "custom_ip_blocklist",
+      "created": 1542400000,
+      "created_by": "jenny@example.com",
+      "item_type": "ip_address",
+      "list_items": {
+        "object": "list",
+        "data": [
+          {
+            "id": "rsli_1",
+            "object": "radar.value_list_item",
+            "created": 1542400000,
+            "created_by": "jenny@example.com",
+            "livemode": false,
+            "value": "1.2.3.4",
+            "value_list": "rsl_1"
+          }
+        ],
+        "has_more": false,
+        "total_count": 1,
+        "url": "/v1/radar/value_list_items?value_list=rsl_1"
+      },
+      "livemode": false,
+      "metadata": {},
+      "name": "Custom IP Blocklist"
+    });
+    let list = serde_json::from_value::<ValueList>(example).unwrap();
+    assert_eq!(list.item_type, ValueListItemType::IpAddress);
+    assert_eq!(list.list_items.data[0].value, "1.2.3.4");
+}
+
+#[test]
+fn deserialize_early_fraud_warning_event() {
+    use stripe::{Event, EventObject, EventType, FraudType};
+
+    let example = json!({
+      "id": "evt_1",
+      "object": "event",
+      "type": "radar.early_fraud_warning.created",
+      "data": {
+        "object": {
+          "id": "issfr_1",
+          "object": "radar.early_fraud_warning",
+          "actionable": true,
+          "charge": "ch_1",
+          "created": 1542400000,
+          "fraud_type": "made_with_stolen_card",
+          "livemode": false
+        }
+      }
+    });
+    let event = serde_json::from_value::<Event>(example).unwrap();
+    assert_eq!(event.event_type, EventType::RadarEarlyFraudWarningCreated);
+    match event.data.object {
+        EventObject::EarlyFraudWarning(ref warning) => {
+            assert!(warning.actionable);
+            assert_eq!(warning.charge.id(), "ch_1");
+            assert_eq!(warning.fraud_type, FraudType::MadeWithStolenCard);
+        }
+        ref other => panic!("expected an early fraud warning; was {:?}", other),
+    }
+}
+
+#[test]
+fn deserialize_payment_method() {
+    use stripe::{PaymentMethod, PaymentMethodCardBrand, PaymentMethodType};
+
+    let example = json!({
+      "id": "pm_1",
+      "object": "payment_method",
+      "billing_details": {
+        "address": {
+          "city": null,
+          "country": null,
+          "line1": null,
+          "line2": null,
+          "postal_code": "42424",
+          "state": null
+        },
+        "email": "jenny@example.com",
+        "name": null,
+        "phone": null
+      },
+      "card": {
+        "brand": "visa",
+        "checks": {
+          "address_line1_check": null,
+          "address_postal_code_check": "pass",
+          "cvc_check": "pass"
+        },
+        "country": "US",
+        "exp_month": 8,
+        "exp_year": 2022,
+        "fingerprint": "Xt5EWLLDS7FJjR1c",
+        "funding": "credit",
+        "last4": "4242",
+        "three_d_secure_usage": { "supported": true },
+        "wallet": null
+      },
+      "created": 1556596206,
+      "customer": "cus_1",
+      "livemode": false,
+      "metadata": {},
+      "type": "card"
+    });
+    let method = serde_json::from_value::<PaymentMethod>(example).unwrap();
+    assert_eq!(method.payment_method_type, PaymentMethodType::Card);
+    assert_eq!(method.card.unwrap().brand, PaymentMethodCardBrand::Visa);
+    assert_eq!(method.billing_details.email.as_deref(), Some("jenny@example.com"));
+}
+
+#[test]
+fn serialize_payment_method_params() {
+    use stripe::{
+        Currency, PaymentIntentCreateParams, PaymentMethodCardParams, PaymentMethodParams,
+        PaymentMethodType, SetupFutureUsage,
+    };
+
+    let params = PaymentMethodParams {
+        payment_method_type: PaymentMethodType::Card,
+        billing_details: None,
+        card: Some(PaymentMethodCardParams::Details {
+            number: "4242424242424242",
+            exp_month: 8,
+            exp_year: 2022,
+            cvc: Some("314"),
+        }),
+        metadata: None,
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(
+        encoded,
+        "type=card&card[number]=4242424242424242&card[exp_month]=8&card[exp_year]=2022&card[cvc]=314"
+    );
+
+    let params = PaymentIntentCreateParams {
+        amount: 1099,
+        currency: Currency::USD,
+        customer: Some("cus_1".to_string()),
+        payment_method: Some("pm_1".to_string()),
+        payment_method_types: vec![PaymentMethodType::Card],
+        setup_future_usage: Some(SetupFutureUsage::OffSession),
+        ..Default::default()
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(
+        encoded,
+        "amount=1099&currency=usd&customer=cus_1&payment_method=pm_1\
+         &payment_method_types[0]=card&setup_future_usage=off_session"
+    );
+}
+
+#[test]
+fn deserialize_setup_intent() {
+    use stripe::{NextActionType, SetupIntent, SetupIntentStatus, SetupIntentUsage};
+
+    let example = json!({
+      "id": "seti_1",
+      "object": "setup_intent",
+      "application": null,
+      "cancellation_reason": null,
+      "client_secret": "seti_1_secret_abc",
+      "created": 1561677666,
+      "customer": "cus_1",
+      "description": null,
+      "last_setup_error": null,
+      "livemode": false,
+      "metadata": {},
+      "next_action": {
+        "type": "redirect_to_url",
+        "redirect_to_url": {
+          "return_url": "https://example.com/setup/complete",
+          "url": "https://hooks.stripe.com/3d_secure/authenticate"
+        }
+      },
+      "on_behalf_of": null,
+      "payment_method": "pm_1",
+      "payment_method_types": ["card"],
+      "status": "requires_action",
+      "usage": "off_session"
+    });
+    let intent = serde_json::from_value::<SetupIntent>(example).unwrap();
+    assert_eq!(intent.status, SetupIntentStatus::RequiresAction);
+    assert_eq!(intent.usage, SetupIntentUsage::OffSession);
+    assert_eq!(intent.customer.as_ref().map(|id| id.as_str()), Some("cus_1"));
+    let next_action = intent.next_action.unwrap();
+    assert_eq!(next_action.action_type, NextActionType::RedirectToUrl);
+    assert!(next_action.redirect_to_url.unwrap().url.is_some());
+}
+
+#[test]
+fn serialize_setup_intent_list_params() {
+    use stripe::SetupIntentListParams;
+
+    let params = SetupIntentListParams {
+        customer: Some("cus_1".parse().unwrap()),
+        limit: Some(3),
+        ..Default::default()
+    };
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "customer=cus_1&limit=3");
+}